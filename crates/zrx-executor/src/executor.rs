@@ -25,16 +25,27 @@
 
 //! Executor.
 
+use crossbeam::channel::bounded;
+use std::panic::UnwindSafe;
 use std::rc::Rc;
-use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use std::{result, thread};
 
+mod delayed;
 mod error;
+mod idle;
+mod metrics;
+mod panics;
+mod scope;
 mod signal;
 pub mod strategy;
 pub mod task;
 
+use delayed::Delayed;
 pub use error::{Error, Result};
+pub use metrics::ExecutorMetrics;
+pub use panics::{PanicHandler, PanicInfo};
+pub use scope::Scope;
 use strategy::{Strategy, WorkSharing};
 use task::Task;
 
@@ -96,6 +107,8 @@ where
 {
     // Execution strategy.
     strategy: Rc<S>,
+    // Delayed tasks.
+    delayed: Rc<Delayed>,
 }
 
 // ----------------------------------------------------------------------------
@@ -119,7 +132,10 @@ where
     /// ```
     #[must_use]
     pub fn new(strategy: S) -> Self {
-        Self { strategy: Rc::new(strategy) }
+        Self {
+            strategy: Rc::new(strategy),
+            delayed: Rc::new(Delayed::new()),
+        }
     }
 
     /// Submits a task.
@@ -212,15 +228,166 @@ where
     where
         T: Into<Box<dyn Task>>,
     {
+        self.release()?;
         self.strategy.submit(task.into())
     }
 
+    /// Attempts to submit a boxed task, handing it back on rejection.
+    ///
+    /// In contrast to [`Executor::submit`], which returns the rejected task
+    /// wrapped in [`Error::Submit`], this method returns the boxed task itself,
+    /// which is more convenient for retry loops, as the task can be passed to
+    /// this method again once the execution strategy has capacity.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying execution strategy is at capacity, the task is handed
+    /// back to the caller, so it can be resubmitted at a later time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::Executor;
+    ///
+    /// // Create executor and submit task
+    /// let executor = Executor::default();
+    /// if let Err(task) = executor.try_submit(Box::new(|| println!("Task"))) {
+    ///     executor.wait();
+    ///     assert!(executor.try_submit(task).is_ok());
+    /// }
+    /// ```
+    #[allow(clippy::missing_panics_doc)]
+    #[inline]
+    pub fn try_submit(
+        &self, task: Box<dyn Task>,
+    ) -> result::Result<(), Box<dyn Task>> {
+        self.submit(task).map_err(|err| match err {
+            Error::Submit(task) => task,
+            // Execution strategies only ever reject tasks by returning them,
+            // so other errors can't possibly occur during task submission
+            Error::Signal => unreachable!("invariant"),
+        })
+    }
+
+    /// Submits a chain of tasks, executing them strictly in order.
+    ///
+    /// This method submits the first stage of the chain, and arranges for each
+    /// stage to return the next stage as a subtask once it's been executed, so
+    /// stages never run concurrently, which is convenient for linear pipelines
+    /// where each stage depends on the effects of the previous one. Subtasks
+    /// returned by a stage are submitted alongside the next stage, but aren't
+    /// waited for. If the chain is empty, nothing is submitted.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying execution strategy is at capacity, the first stage
+    /// is returned as part of [`Error::Submit`], wrapping the entire chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_executor::task::Task;
+    /// use zrx_executor::Executor;
+    ///
+    /// // Create stages of chain
+    /// let mut stages: Vec<Box<dyn Task>> = Vec::new();
+    /// stages.push(Box::new(|| println!("Stage 1")));
+    /// stages.push(Box::new(|| println!("Stage 2")));
+    /// stages.push(Box::new(|| println!("Stage 3")));
+    ///
+    /// // Create executor and submit chain of tasks
+    /// let executor = Executor::default();
+    /// executor.submit_chain(stages)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn submit_chain(&self, stages: Vec<Box<dyn Task>>) -> Result {
+        let mut iter = stages.into_iter().rev();
+        let Some(last) = iter.next() else {
+            return Ok(());
+        };
+
+        // Wrap stages back to front, so that each stage returns the next one
+        // as a subtask after it was executed, which yields the first stage
+        let task = iter.fold(last, |next, stage| -> Box<dyn Task> {
+            Box::new(move || {
+                let mut tasks = stage.execute();
+                tasks.add(move || next.execute());
+                tasks
+            })
+        });
+        self.submit(task)
+    }
+
+    /// Submits a task after the given delay.
+    ///
+    /// This method holds the [`Task`] in the executor until the given delay
+    /// has passed, and then submits it to the execution strategy. Delayed
+    /// tasks are released whenever a task is submitted, or when waiting for
+    /// the executor via [`Executor::wait`], so there are no timer threads.
+    /// If the execution strategy is at capacity, due tasks are held until
+    /// they can be submitted, which is why this method doesn't return them.
+    ///
+    /// # Errors
+    ///
+    /// If the executor encounters a problem while releasing due tasks, other
+    /// than the execution strategy being at capacity, it's forwarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use std::time::Duration;
+    /// use zrx_executor::Executor;
+    ///
+    /// // Create executor and submit task after delay
+    /// let executor = Executor::default();
+    /// let delay = Duration::from_millis(10);
+    /// executor.submit_after(delay, || println!("Task"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn submit_after<T>(&self, delay: Duration, task: T) -> Result
+    where
+        T: Into<Box<dyn Task>>,
+    {
+        self.delayed.push(Instant::now() + delay, task.into());
+        self.release()
+    }
+
+    /// Releases all delayed tasks that are due to the execution strategy.
+    ///
+    /// Tasks that are rejected by the execution strategy, as it's at capacity,
+    /// are added back with their original deadline, and releasing stops.
+    fn release(&self) -> Result {
+        let now = Instant::now();
+        while let Some((deadline, task)) = self.delayed.pop_due(now) {
+            if let Err(err) = self.strategy.submit(task) {
+                let Error::Submit(task) = err else {
+                    return Err(err);
+                };
+                self.delayed.push(deadline, task);
+                break;
+            }
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+
     /// Waits for all tasks to finish.
     ///
     /// This method blocks the current thread until all submitted running and
     /// pending tasks have been completed. Calling this method is not necessary,
     /// as it's called automatically when the executor is dropped, but it might
-    /// be helpful for testing and debugging purposes.
+    /// be helpful for testing and debugging purposes. Waiting is delegated to
+    /// [`Strategy::wait_idle`], which allows worker-based strategies to wake
+    /// the current thread as soon as they become idle. Delayed tasks are
+    /// waited for as well, releasing them as soon as they're due.
     ///
     /// # Examples
     ///
@@ -250,10 +417,112 @@ where
     /// # }
     /// ```
     pub fn wait(&self) {
-        let duration = Duration::from_millis(10);
-        while !self.is_empty() {
-            thread::sleep(duration);
+        let duration = Duration::from_millis(1);
+
+        // Release delayed tasks until none are left, sleeping until the next
+        // deadline, or retrying shortly when the strategy is at capacity
+        loop {
+            let _ = self.release();
+            let Some(deadline) = self.delayed.deadline() else {
+                break;
+            };
+            let now = Instant::now();
+            thread::sleep(
+                deadline.saturating_duration_since(now).max(duration),
+            );
         }
+
+        // Wait for strategy to become idle
+        self.strategy.wait_idle();
+    }
+
+    /// Applies the given function to all items in parallel.
+    ///
+    /// This method submits a [`Task`] for each item, which applies the given
+    /// function and sends the result back through a dedicated channel. Then,
+    /// results are collected in the order of the given items, regardless of
+    /// the order in which tasks complete. If the underlying strategy is at
+    /// capacity, submission is retried until the task is accepted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the tasks panics, as its result can't be collected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::Executor;
+    ///
+    /// // Create executor and map over items
+    /// let executor = Executor::default();
+    /// let results = executor.map(0..4, |x| x * 2);
+    /// assert_eq!(results, [0, 2, 4, 6]);
+    /// ```
+    pub fn map<I, T, R, F>(&self, items: I, f: F) -> Vec<R>
+    where
+        I: IntoIterator<Item = T>,
+        T: Send + UnwindSafe + 'static,
+        R: Send + 'static,
+        F: Fn(T) -> R + Send + Sync + Clone + UnwindSafe + 'static,
+    {
+        let duration = Duration::from_millis(1);
+
+        // Submit a task for each item, each with its own result channel
+        let iter = items.into_iter().map(|item| {
+            let (sender, receiver) = bounded(1);
+            let f = f.clone();
+            let mut task: Box<dyn Task> = Box::new(move || {
+                let _ = sender.send(f(item));
+            });
+
+            // Resubmit task until the strategy accepts it, in case the
+            // strategy is at capacity and rejects the task
+            while let Err(Error::Submit(rejected)) = self.submit(task) {
+                task = rejected;
+                thread::sleep(duration);
+            }
+            receiver
+        });
+
+        // Collect all receivers before waiting for results, so that all tasks
+        // are submitted first, then collect results in the order of items
+        let receivers = iter.collect::<Vec<_>>();
+        receivers
+            .into_iter()
+            .map(|receiver| receiver.recv().expect("task panicked"))
+            .collect()
+    }
+
+    /// Creates a scope for spawning tasks that borrow from the stack frame.
+    ///
+    /// This method passes a [`Scope`] to the given function, which allows to
+    /// spawn tasks that borrow local data, as this method blocks until all
+    /// tasks spawned in the scope have finished, even if the function panics.
+    /// Other tasks submitted to the executor are not waited for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::Executor;
+    ///
+    /// // Create executor and data
+    /// let executor = Executor::default();
+    /// let mut data = [0; 4];
+    ///
+    /// // Spawn tasks borrowing data
+    /// executor.scope(|scope| {
+    ///     for (n, value) in data.iter_mut().enumerate() {
+    ///         scope.spawn(move || *value = n * 2);
+    ///     }
+    /// });
+    /// assert_eq!(data, [0, 2, 4, 6]);
+    /// ```
+    pub fn scope<'scope, F>(&'scope self, f: F)
+    where
+        F: FnOnce(&Scope<'scope, S>),
+    {
+        let scope = Scope::new(self);
+        f(&scope);
     }
 }
 
@@ -265,7 +534,7 @@ where
     /// Returns the number of tasks.
     ///
     /// This method returns the total number of tasks currently managed by the
-    /// executor, which includes running as well as pending tasks.
+    /// executor, which includes running, pending and delayed tasks.
     ///
     /// # Examples
     ///
@@ -278,13 +547,13 @@ where
     /// ```
     #[inline]
     pub fn len(&self) -> usize {
-        self.num_tasks_running() + self.num_tasks_pending()
+        self.num_tasks_running() + self.num_tasks_pending() + self.delayed.len()
     }
 
     /// Returns whether there are any tasks.
     ///
-    /// This method checks whether the executor has running or pending tasks,
-    /// and if not, considers the executor as idle. It's particularly useful
+    /// This method checks whether the executor has running, pending or delayed
+    /// tasks, and if not, considers the executor as idle. It's particularly useful
     /// for waiting until an executor has processed all tasks, which is
     /// necessary for implementing schedulers on top of executors.
     ///
@@ -299,7 +568,7 @@ where
     /// ```
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.len() == 0
+        self.delayed.is_empty() && self.strategy.is_idle()
     }
 
     /// Returns whether the executor is saturated.
@@ -395,6 +664,63 @@ where
     pub fn capacity(&self) -> Option<usize> {
         self.strategy.capacity()
     }
+
+    /// Sets the panic handler, replacing the previous one, if any.
+    ///
+    /// The handler is invoked with the [`PanicInfo`] of every task panic that
+    /// is caught by the execution strategy, including the name of the task and
+    /// the panic message, if recoverable, which is useful for logging and
+    /// alerting. Panics of the handler itself are caught and ignored, so they
+    /// never take down the worker thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::Executor;
+    ///
+    /// // Create executor and set panic handler
+    /// let executor = Executor::default();
+    /// executor.set_panic_handler(|info| eprintln!("{info:?}"));
+    /// ```
+    #[inline]
+    pub fn set_panic_handler<F>(&self, f: F)
+    where
+        F: Fn(PanicInfo) + Send + Sync + 'static,
+    {
+        self.strategy.set_panic_handler(Box::new(f));
+    }
+
+    /// Returns a snapshot of the metrics.
+    ///
+    /// This method reads all counters of the executor in one call, deriving
+    /// whether the executor is saturated from the same number of pending
+    /// tasks, so the snapshot is consistent in itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::strategy::WorkSharing;
+    /// use zrx_executor::Executor;
+    ///
+    /// // Get metrics
+    /// let executor = Executor::new(WorkSharing::new(1));
+    /// let metrics = executor.metrics();
+    /// assert_eq!(metrics.workers, 1);
+    /// assert_eq!(metrics.running, 0);
+    /// ```
+    #[inline]
+    pub fn metrics(&self) -> ExecutorMetrics {
+        let pending = self.num_tasks_pending();
+        let capacity = self.capacity();
+        ExecutorMetrics {
+            workers: self.num_workers(),
+            running: self.num_tasks_running(),
+            pending,
+            delayed: self.delayed.len(),
+            capacity,
+            saturated: capacity.is_some_and(|capacity| pending >= capacity),
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -423,6 +749,7 @@ where
     fn clone(&self) -> Self {
         Self {
             strategy: Rc::clone(&self.strategy),
+            delayed: Rc::clone(&self.delayed),
         }
     }
 }
@@ -453,3 +780,386 @@ where
         self.wait();
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod wait {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+        use std::time::{Duration, Instant};
+
+        use crate::executor::strategy::{WorkSharing, WorkStealing};
+        use crate::executor::{Executor, Result};
+
+        #[test]
+        fn handles_work_sharing() -> Result {
+            let executor = Executor::new(WorkSharing::new(2));
+            let mut elapsed = Duration::MAX;
+            for _ in 0..5 {
+                let done = Arc::new(AtomicBool::new(false));
+                let flag = Arc::clone(&done);
+                let instant = Instant::now();
+                executor.submit(move || flag.store(true, Ordering::Release))?;
+                executor.wait();
+                elapsed = elapsed.min(instant.elapsed());
+                assert!(done.load(Ordering::Acquire));
+            }
+            assert!(elapsed < Duration::from_millis(5));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_work_stealing() -> Result {
+            let executor = Executor::new(WorkStealing::new(2));
+            let mut elapsed = Duration::MAX;
+            for _ in 0..5 {
+                let done = Arc::new(AtomicBool::new(false));
+                let flag = Arc::clone(&done);
+                let instant = Instant::now();
+                executor.submit(move || flag.store(true, Ordering::Release))?;
+                executor.wait();
+                elapsed = elapsed.min(instant.elapsed());
+                assert!(done.load(Ordering::Acquire));
+            }
+            assert!(elapsed < Duration::from_millis(5));
+            Ok(())
+        }
+    }
+
+    mod try_submit {
+        use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use std::thread;
+        use std::time::Duration;
+
+        use crate::executor::strategy::WorkSharing;
+        use crate::executor::task::Task;
+        use crate::executor::Executor;
+
+        #[test]
+        fn handles_capacity() {
+            let executor = Executor::new(WorkSharing::with_capacity(1, 1));
+            let done = Arc::new(AtomicBool::new(false));
+            let flag = Arc::clone(&done);
+            let block: Box<dyn Task> = Box::new(move || {
+                while !flag.load(Ordering::Acquire) {
+                    thread::sleep(Duration::from_millis(1));
+                }
+            });
+            assert!(executor.try_submit(block).is_ok());
+            while executor.num_tasks_running() == 0 {
+                thread::sleep(Duration::from_millis(1));
+            }
+
+            // Fill the channel, so the next task is handed back
+            let count = Arc::new(AtomicUsize::new(0));
+            let clone = Arc::clone(&count);
+            assert!(executor.try_submit(Box::new(|| {})).is_ok());
+            let res = executor.try_submit(Box::new(move || {
+                clone.fetch_add(1, Ordering::Relaxed);
+            }));
+            let Err(task) = res else {
+                panic!("task should be rejected");
+            };
+
+            // Drain the executor, and resubmit the rejected task
+            done.store(true, Ordering::Release);
+            executor.wait();
+            assert!(executor.try_submit(task).is_ok());
+            executor.wait();
+            assert_eq!(count.load(Ordering::Relaxed), 1);
+        }
+    }
+
+    mod submit_chain {
+        use std::sync::{Arc, Mutex};
+        use std::thread;
+        use std::time::Duration;
+
+        use crate::executor::strategy::{WorkSharing, WorkStealing};
+        use crate::executor::task::Task;
+        use crate::executor::{Executor, Result};
+
+        fn stages(order: &Arc<Mutex<Vec<usize>>>) -> Vec<Box<dyn Task>> {
+            (0..3)
+                .map(|n| -> Box<dyn Task> {
+                    let order = Arc::clone(order);
+                    Box::new(move || {
+                        thread::sleep(Duration::from_millis(3 - n as u64));
+                        order.lock().unwrap().push(n);
+                    })
+                })
+                .collect()
+        }
+
+        #[test]
+        fn handles_work_sharing() -> Result {
+            let executor = Executor::new(WorkSharing::new(4));
+            let order = Arc::new(Mutex::new(Vec::new()));
+            executor.submit_chain(stages(&order))?;
+            executor.wait();
+            assert_eq!(*order.lock().unwrap(), Vec::from([0, 1, 2]));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_work_stealing() -> Result {
+            let executor = Executor::new(WorkStealing::new(4));
+            let order = Arc::new(Mutex::new(Vec::new()));
+            executor.submit_chain(stages(&order))?;
+            executor.wait();
+            assert_eq!(*order.lock().unwrap(), Vec::from([0, 1, 2]));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_empty() -> Result {
+            let executor = Executor::new(WorkSharing::new(1));
+            executor.submit_chain(Vec::new())?;
+            executor.wait();
+            assert!(executor.is_empty());
+            Ok(())
+        }
+    }
+
+    mod submit_after {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+        use std::thread;
+        use std::time::Duration;
+
+        use crate::executor::strategy::{WorkSharing, WorkStealing};
+        use crate::executor::{Executor, Result};
+
+        #[test]
+        fn handles_work_sharing() -> Result {
+            let executor = Executor::new(WorkSharing::new(2));
+            let done = Arc::new(AtomicBool::new(false));
+            let flag = Arc::clone(&done);
+            let delay = Duration::from_millis(30);
+            executor.submit_after(delay, move || {
+                flag.store(true, Ordering::Release);
+            })?;
+            thread::sleep(Duration::from_millis(10));
+            assert!(!done.load(Ordering::Acquire));
+            assert!(!executor.is_empty());
+            executor.wait();
+            assert!(done.load(Ordering::Acquire));
+            assert!(executor.is_empty());
+            Ok(())
+        }
+
+        #[test]
+        fn handles_work_stealing() -> Result {
+            let executor = Executor::new(WorkStealing::new(2));
+            let done = Arc::new(AtomicBool::new(false));
+            let flag = Arc::clone(&done);
+            let delay = Duration::from_millis(30);
+            executor.submit_after(delay, move || {
+                flag.store(true, Ordering::Release);
+            })?;
+            thread::sleep(Duration::from_millis(10));
+            assert!(!done.load(Ordering::Acquire));
+            assert!(!executor.is_empty());
+            executor.wait();
+            assert!(done.load(Ordering::Acquire));
+            assert!(executor.is_empty());
+            Ok(())
+        }
+    }
+
+    mod metrics {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+        use std::thread;
+        use std::time::Duration;
+
+        use crate::executor::strategy::WorkSharing;
+        use crate::executor::{Executor, ExecutorMetrics, Result};
+
+        #[test]
+        fn handles_work_sharing() -> Result {
+            let executor = Executor::new(WorkSharing::new(1));
+            let done = Arc::new(AtomicBool::new(false));
+            for _ in 0..3 {
+                let flag = Arc::clone(&done);
+                executor.submit(move || {
+                    while !flag.load(Ordering::Acquire) {
+                        thread::sleep(Duration::from_millis(1));
+                    }
+                })?;
+            }
+            executor.submit_after(Duration::from_millis(50), || {})?;
+            thread::sleep(Duration::from_millis(10));
+            let metrics = executor.metrics();
+            assert_eq!(metrics.workers, 1);
+            assert_eq!(metrics.running, 1);
+            assert_eq!(metrics.pending, 2);
+            assert_eq!(metrics.delayed, 1);
+            assert_eq!(
+                metrics.running + metrics.pending + metrics.delayed,
+                executor.len()
+            );
+            assert_eq!(
+                metrics.saturated,
+                metrics.capacity.is_some_and(|n| metrics.pending >= n)
+            );
+            done.store(true, Ordering::Release);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_idle() {
+            let executor = Executor::new(WorkSharing::new(2));
+            assert_eq!(
+                executor.metrics(),
+                ExecutorMetrics {
+                    workers: 2,
+                    capacity: executor.capacity(),
+                    ..ExecutorMetrics::default()
+                }
+            );
+        }
+    }
+
+    mod set_panic_handler {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::{Arc, Mutex};
+
+        use crate::executor::strategy::{WorkSharing, WorkStealing};
+        use crate::executor::{Executor, PanicInfo, Result};
+
+        fn render() {
+            panic!("render failed");
+        }
+
+        #[test]
+        fn handles_work_sharing() -> Result {
+            let executor = Executor::new(WorkSharing::new(1));
+            let count = Arc::new(AtomicUsize::new(0));
+            let clone = Arc::clone(&count);
+            executor.set_panic_handler(move |_| {
+                clone.fetch_add(1, Ordering::Relaxed);
+            });
+            executor.submit(render)?;
+            executor.submit(render)?;
+            executor.wait();
+            assert_eq!(count.load(Ordering::Relaxed), 2);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_work_stealing() -> Result {
+            let executor = Executor::new(WorkStealing::new(1));
+            let infos = Arc::new(Mutex::new(Vec::new()));
+            let clone = Arc::clone(&infos);
+            executor.set_panic_handler(move |info| {
+                clone.lock().unwrap().push(info);
+            });
+            executor.submit(render)?;
+            executor.wait();
+            assert_eq!(
+                infos.lock().unwrap().as_slice(),
+                [PanicInfo {
+                    name: None,
+                    message: Some(String::from("render failed")),
+                }]
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_panicking_handler() -> Result {
+            let executor = Executor::new(WorkSharing::new(1));
+            let count = Arc::new(AtomicUsize::new(0));
+            let clone = Arc::clone(&count);
+            executor.set_panic_handler(move |_| {
+                clone.fetch_add(1, Ordering::Relaxed);
+                panic!("handler failed");
+            });
+            executor.submit(render)?;
+            executor.submit(render)?;
+            executor.wait();
+            assert_eq!(count.load(Ordering::Relaxed), 2);
+            Ok(())
+        }
+    }
+
+    mod scope {
+        use crate::executor::strategy::{Immediate, WorkSharing, WorkStealing};
+        use crate::executor::Executor;
+
+        #[test]
+        fn handles_work_sharing() {
+            let executor = Executor::new(WorkSharing::with_capacity(4, 4));
+            let mut data = vec![0; 100];
+            executor.scope(|scope| {
+                for (n, chunk) in data.chunks_mut(10).enumerate() {
+                    scope.spawn(move || chunk.fill(n));
+                }
+            });
+            assert_eq!(data, (0..100).map(|n| n / 10).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn handles_work_stealing() {
+            let executor = Executor::new(WorkStealing::new(4));
+            let mut data = vec![0; 100];
+            executor.scope(|scope| {
+                for (n, chunk) in data.chunks_mut(10).enumerate() {
+                    scope.spawn(move || chunk.fill(n));
+                }
+            });
+            assert_eq!(data, (0..100).map(|n| n / 10).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn handles_immediate() {
+            let executor = Executor::new(Immediate::new());
+            let mut data = vec![0; 100];
+            executor.scope(|scope| {
+                for (n, chunk) in data.chunks_mut(10).enumerate() {
+                    scope.spawn(move || chunk.fill(n));
+                }
+            });
+            assert_eq!(data, (0..100).map(|n| n / 10).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn handles_panic() {
+            let executor = Executor::new(WorkSharing::new(2));
+            let mut data = vec![0; 2];
+            executor.scope(|scope| {
+                let (a, b) = data.split_at_mut(1);
+                scope.spawn(|| panic!("task panicked"));
+                scope.spawn(move || a[0] = 1);
+                scope.spawn(move || b[0] = 2);
+            });
+            assert_eq!(data, [1, 2]);
+        }
+    }
+
+    mod map {
+        use crate::executor::strategy::{WorkSharing, WorkStealing};
+        use crate::executor::Executor;
+
+        #[test]
+        fn handles_work_sharing() {
+            let executor = Executor::new(WorkSharing::with_capacity(4, 4));
+            let results = executor.map(0..100, |x| x * 2);
+            assert_eq!(results, (0..100).map(|x| x * 2).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn handles_work_stealing() {
+            let executor = Executor::new(WorkStealing::new(4));
+            let results = executor.map(0..100, |x| x * 2);
+            assert_eq!(results, (0..100).map(|x| x * 2).collect::<Vec<_>>());
+        }
+    }
+}
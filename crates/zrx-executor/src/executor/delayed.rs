@@ -0,0 +1,149 @@
+// Copyright (c) 2025-2026 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// All contributions are certified under the DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Delayed tasks.
+
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::time::Instant;
+
+use super::task::Task;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Delayed tasks.
+///
+/// Tasks are ordered by their deadlines, so that the task with the earliest
+/// deadline can be obtained in constant time. Tasks are held until they're
+/// released by the executor, which happens once their deadline has passed.
+#[derive(Default)]
+pub struct Delayed {
+    /// Heap of delayed tasks.
+    heap: RefCell<BinaryHeap<Entry>>,
+}
+
+/// Delayed task.
+struct Entry {
+    /// Deadline of task.
+    deadline: Instant,
+    /// Task.
+    task: Box<dyn Task>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Delayed {
+    /// Creates a collection of delayed tasks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a task that is due at the given deadline.
+    pub fn push(&self, deadline: Instant, task: Box<dyn Task>) {
+        self.heap.borrow_mut().push(Entry { deadline, task });
+    }
+
+    /// Removes and returns the next task, if it's due at the given instant.
+    ///
+    /// The deadline is returned alongside the task, so it can be added again
+    /// with the same deadline in case the task can't be submitted.
+    pub fn pop_due(&self, now: Instant) -> Option<(Instant, Box<dyn Task>)> {
+        let mut heap = self.heap.borrow_mut();
+        if heap.peek()?.deadline > now {
+            return None;
+        }
+
+        // Remove and return task, which we know is due
+        heap.pop().map(|entry| (entry.deadline, entry.task))
+    }
+
+    /// Returns the earliest deadline of all tasks.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.heap.borrow().peek().map(|entry| entry.deadline)
+    }
+
+    /// Returns the number of tasks.
+    pub fn len(&self) -> usize {
+        self.heap.borrow().len()
+    }
+
+    /// Returns whether there are any tasks.
+    pub fn is_empty(&self) -> bool {
+        self.heap.borrow().is_empty()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl fmt::Debug for Delayed {
+    /// Formats the delayed tasks for debugging.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Delayed")
+            .field("len", &self.len())
+            .field("deadline", &self.deadline())
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl PartialEq for Entry {
+    /// Compares two delayed tasks for equality by their deadlines.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.deadline == other.deadline
+    }
+}
+
+impl Eq for Entry {}
+
+// ----------------------------------------------------------------------------
+
+impl PartialOrd for Entry {
+    /// Orders two delayed tasks by their deadlines.
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    /// Orders two delayed tasks by their deadlines.
+    ///
+    /// The ordering is reversed, since [`BinaryHeap`] is a max-heap, and we
+    /// need to obtain the task with the earliest deadline first.
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.deadline.cmp(&self.deadline)
+    }
+}
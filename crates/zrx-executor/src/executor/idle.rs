@@ -0,0 +1,93 @@
+// Copyright (c) 2025-2026 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// All contributions are certified under the DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Idle notification.
+
+use std::sync::{Condvar, Mutex, PoisonError};
+use std::time::Duration;
+
+// ----------------------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------------------
+
+/// Maximum duration a waiting thread blocks before re-checking for idleness.
+const TIMEOUT: Duration = Duration::from_millis(100);
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Idle notification.
+#[derive(Default)]
+pub struct Idle {
+    /// Lock to prevent missed notifications.
+    mutex: Mutex<()>,
+    /// Condition to block thread without busy-waiting.
+    value: Condvar,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Idle {
+    /// Creates an idle notification.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Blocks the current thread until the given condition holds.
+    ///
+    /// This method is used by the executor to wait until a strategy is idle,
+    /// which is re-checked whenever a worker signals that it became idle, or
+    /// the timeout elapses, whichever comes first. The timeout only bounds the
+    /// latency in case a strategy doesn't signal all transitions to idle, as
+    /// the lock prevents notifications from being missed.
+    pub fn wait<F>(&self, is_idle: F)
+    where
+        F: Fn() -> bool,
+    {
+        let mut guard =
+            self.mutex.lock().unwrap_or_else(PoisonError::into_inner);
+        while !is_idle() {
+            guard = self
+                .value
+                .wait_timeout(guard, TIMEOUT)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+    }
+
+    /// Notifies all waiting threads.
+    ///
+    /// This method is called by workers whenever they transition to idle. The
+    /// lock is acquired before notifying, so that a thread that just checked
+    /// the condition is guaranteed to be waiting when it's notified.
+    pub fn notify(&self) {
+        let guard = self.mutex.lock().unwrap_or_else(PoisonError::into_inner);
+        drop(guard);
+        self.value.notify_all();
+    }
+}
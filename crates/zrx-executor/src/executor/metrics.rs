@@ -0,0 +1,55 @@
+// Copyright (c) 2025-2026 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// All contributions are certified under the DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Executor metrics.
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Executor metrics.
+///
+/// Metrics are a snapshot of the state of an [`Executor`][] obtained in one
+/// call via [`Executor::metrics`][], which is convenient for monitoring. Note
+/// that the counters are read one after another while workers make progress,
+/// so the snapshot is consistent in itself, but not guaranteed to be atomic.
+///
+/// [`Executor`]: crate::executor::Executor
+/// [`Executor::metrics`]: crate::executor::Executor::metrics
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExecutorMetrics {
+    /// Number of workers.
+    pub workers: usize,
+    /// Number of running tasks.
+    pub running: usize,
+    /// Number of pending tasks.
+    pub pending: usize,
+    /// Number of delayed tasks.
+    pub delayed: usize,
+    /// Capacity, if bounded.
+    pub capacity: Option<usize>,
+    /// Whether the executor is saturated.
+    pub saturated: bool,
+}
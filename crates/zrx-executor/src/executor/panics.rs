@@ -0,0 +1,152 @@
+// Copyright (c) 2025-2026 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// All contributions are certified under the DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Panic record.
+
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Panic information.
+///
+/// This data type is passed to the panic handler that can be registered with
+/// [`Executor::set_panic_handler`][], and describes a panic of a task caught
+/// by one of the worker execution strategies.
+///
+/// [`Executor::set_panic_handler`]: crate::executor::Executor::set_panic_handler
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PanicInfo {
+    /// Name of the task, if any.
+    pub name: Option<String>,
+    /// Panic message, if recoverable.
+    pub message: Option<String>,
+}
+
+/// Panic record.
+#[derive(Default)]
+pub struct Panics {
+    /// Counter for panicked tasks.
+    count: AtomicUsize,
+    /// Name of the last panicked task, if any.
+    last: Mutex<Option<String>>,
+    /// Panic handler, if any.
+    handler: RwLock<Option<Arc<PanicHandler>>>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Panics {
+    /// Creates a panic record.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a panic of the task with the given name.
+    ///
+    /// This method is called by workers whenever they catch a panic of a task,
+    /// which is additionally logged, if the `tracing` feature is enabled. The
+    /// message is recovered from the payload, if it's a string, and passed to
+    /// the panic handler together with the name of the task, if registered.
+    pub fn record(&self, name: Option<String>, payload: &(dyn Any + Send)) {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| (*message).to_owned())
+            .or_else(|| payload.downcast_ref::<String>().cloned());
+
+        #[cfg(feature = "tracing")]
+        tracing::error!(
+            task = name.as_deref(),
+            message = message.as_deref(),
+            "task panicked"
+        );
+
+        // Update name before counter, so it's visible once the count changes
+        let mut last = self.last.lock().unwrap_or_else(PoisonError::into_inner);
+        last.clone_from(&name);
+        drop(last);
+        self.count.fetch_add(1, Ordering::Release);
+
+        // Obtain panic handler, if any, and release the lock before invoking
+        // it, so the handler is free to replace itself without deadlocking
+        let guard = self.handler.read().unwrap_or_else(PoisonError::into_inner);
+        let handler = guard.clone();
+        drop(guard);
+
+        // Invoke panic handler, if any, and catch panics, as the handler is
+        // user-land code as well, which must never take down the worker
+        if let Some(handler) = handler {
+            let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+                handler(PanicInfo { name, message });
+            }));
+        }
+    }
+
+    /// Sets the panic handler, replacing the previous one, if any.
+    pub fn set_handler(&self, handler: Box<PanicHandler>) {
+        let mut current =
+            self.handler.write().unwrap_or_else(PoisonError::into_inner);
+        *current = Some(Arc::from(handler));
+    }
+
+    /// Returns the number of panicked tasks.
+    pub fn count(&self) -> usize {
+        self.count.load(Ordering::Acquire)
+    }
+
+    /// Returns the name of the last panicked task, if any.
+    pub fn last(&self) -> Option<String> {
+        let last = self.last.lock().unwrap_or_else(PoisonError::into_inner);
+        last.clone()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl fmt::Debug for Panics {
+    /// Formats the panic record for debugging.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Panics")
+            .field("count", &self.count())
+            .field("last", &self.last())
+            .finish_non_exhaustive()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Type aliases
+// ----------------------------------------------------------------------------
+
+/// Panic handler.
+pub type PanicHandler = dyn Fn(PanicInfo) + Send + Sync;
@@ -0,0 +1,258 @@
+// Copyright (c) 2025-2026 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// All contributions are certified under the DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Scope for structured parallelism.
+
+use std::marker::PhantomData;
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+use std::{fmt, mem, thread};
+
+use super::strategy::Strategy;
+use super::task::{Task, Tasks};
+use super::{Error, Executor};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Scope for structured parallelism.
+///
+/// Scopes are created via [`Executor::scope`], and allow to spawn tasks that
+/// borrow from the enclosing stack frame, since the scope waits for all of its
+/// tasks to finish when it's dropped. This is also the case when the closure
+/// that spawns tasks panics, which guarantees that tasks never outlive the
+/// data they borrow.
+///
+/// # Examples
+///
+/// ```
+/// use zrx_executor::Executor;
+///
+/// // Create executor and data
+/// let executor = Executor::default();
+/// let mut data = [0; 4];
+///
+/// // Spawn tasks borrowing data
+/// executor.scope(|scope| {
+///     for (n, value) in data.iter_mut().enumerate() {
+///         scope.spawn(move || *value = n * 2);
+///     }
+/// });
+/// assert_eq!(data, [0, 2, 4, 6]);
+/// ```
+pub struct Scope<'scope, S>
+where
+    S: Strategy,
+{
+    /// Executor.
+    executor: &'scope Executor<S>,
+    /// Counter of pending tasks.
+    counter: Arc<Counter>,
+    /// Capture invariant lifetime.
+    marker: PhantomData<&'scope mut &'scope ()>,
+}
+
+/// Counter of pending tasks.
+#[derive(Default)]
+struct Counter {
+    /// Number of pending tasks.
+    mutex: Mutex<usize>,
+    /// Condition to block thread until all tasks are done.
+    value: Condvar,
+}
+
+/// Task spawned in a scope.
+struct Job {
+    /// Task with erased lifetime.
+    task: AssertUnwindSafe<Option<Box<dyn FnOnce() + Send>>>,
+    /// Counter of pending tasks.
+    counter: Arc<Counter>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<'scope, S> Scope<'scope, S>
+where
+    S: Strategy,
+{
+    /// Creates a scope.
+    pub(super) fn new(executor: &'scope Executor<S>) -> Self {
+        Self {
+            executor,
+            counter: Arc::default(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Spawns a task in the scope.
+    ///
+    /// The task may borrow from the stack frame enclosing the scope, as it's
+    /// guaranteed to finish before [`Executor::scope`] returns. If the
+    /// underlying strategy is at capacity, submission is retried until the
+    /// task is accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use zrx_executor::Executor;
+    ///
+    /// // Create executor and counter
+    /// let executor = Executor::default();
+    /// let counter = AtomicUsize::new(0);
+    ///
+    /// // Spawn tasks borrowing counter
+    /// executor.scope(|scope| {
+    ///     for _ in 0..4 {
+    ///         scope.spawn(|| {
+    ///             counter.fetch_add(1, Ordering::Relaxed);
+    ///         });
+    ///     }
+    /// });
+    /// assert_eq!(counter.load(Ordering::Relaxed), 4);
+    /// ```
+    pub fn spawn<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'scope,
+    {
+        let duration = Duration::from_millis(1);
+        let task: Box<dyn FnOnce() + Send + 'scope> = Box::new(f);
+
+        // SAFETY: the scope waits for all tasks to finish when it's dropped,
+        // which happens before the borrowed data goes out of scope, and tasks
+        // are never handed out, so erasing the lifetime can't be observed
+        let task = unsafe {
+            mem::transmute::<
+                Box<dyn FnOnce() + Send + 'scope>,
+                Box<dyn FnOnce() + Send + 'static>,
+            >(task)
+        };
+
+        // Increment the counter before submission, as the task might complete
+        // before we get the chance to, which would be a race condition
+        self.counter.increment();
+        let mut task: Box<dyn Task> = Box::new(Job {
+            task: AssertUnwindSafe(Some(task)),
+            counter: Arc::clone(&self.counter),
+        });
+
+        // Resubmit task until the strategy accepts it, in case the strategy is
+        // at capacity and rejects the task. If the task is dropped for another
+        // reason, dropping it decrements the counter, so we can't deadlock.
+        while let Err(Error::Submit(rejected)) = self.executor.submit(task) {
+            task = rejected;
+            thread::sleep(duration);
+        }
+    }
+}
+
+impl Counter {
+    /// Increments the number of pending tasks.
+    fn increment(&self) {
+        *self.lock() += 1;
+    }
+
+    /// Decrements the number of pending tasks, notifying if none are left.
+    fn decrement(&self) {
+        let mut guard = self.lock();
+        *guard -= 1;
+        if *guard == 0 {
+            self.value.notify_all();
+        }
+    }
+
+    /// Blocks the current thread until there are no more pending tasks.
+    fn wait(&self) {
+        let guard = self.lock();
+        drop(
+            self.value
+                .wait_while(guard, |count| *count > 0)
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+    }
+
+    /// Acquires the lock, ignoring poisoning, since the lock is never held
+    /// while executing tasks, so the number can't be left inconsistent.
+    fn lock(&self) -> MutexGuard<'_, usize> {
+        self.mutex.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl Task for Job {
+    /// Executes the task.
+    #[inline]
+    fn execute(mut self: Box<Self>) -> Tasks {
+        if let Some(task) = self.task.take() {
+            task();
+        }
+        Tasks::new()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<S> fmt::Debug for Scope<'_, S>
+where
+    S: Strategy,
+{
+    /// Formats the scope for debugging.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Scope")
+            .field("executor", &self.executor)
+            .finish_non_exhaustive()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<S> Drop for Scope<'_, S>
+where
+    S: Strategy,
+{
+    /// Waits for all tasks spawned in the scope to finish.
+    fn drop(&mut self) {
+        self.counter.wait();
+    }
+}
+
+impl Drop for Job {
+    /// Drops the task and decrements the number of pending tasks.
+    ///
+    /// The task is dropped before the counter is decremented, since it might
+    /// capture borrowed data, which must not be accessed after the scope has
+    /// finished waiting. This also covers tasks that panic or never execute.
+    fn drop(&mut self) {
+        drop(self.task.take());
+        self.counter.decrement();
+    }
+}
@@ -25,10 +25,20 @@
 
 //! Signal for synchronization.
 
+#[cfg(test)]
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Condvar, Mutex};
+use std::time::Duration;
 
 use super::error::{Error, Result};
 
+// ----------------------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------------------
+
+/// Maximum duration a worker waits before re-checking for tasks.
+const TIMEOUT: Duration = Duration::from_millis(100);
+
 // ----------------------------------------------------------------------------
 // Structs
 // ----------------------------------------------------------------------------
@@ -39,6 +49,9 @@ pub struct Signal {
     mutex: Mutex<bool>,
     /// Condition to block thread without busy-waiting.
     value: Condvar,
+    /// Counter for wakeups, used to verify that idle workers are parked.
+    #[cfg(test)]
+    wakeups: AtomicUsize,
 }
 
 // ----------------------------------------------------------------------------
@@ -51,6 +64,8 @@ impl Signal {
         Self {
             mutex: Mutex::new(false),
             value: Condvar::new(),
+            #[cfg(test)]
+            wakeups: AtomicUsize::new(0),
         }
     }
 
@@ -58,24 +73,32 @@ impl Signal {
     ///
     /// This method is used inside a worker when there're no more tasks to be
     /// executed to hand back control to the executor, allowing the worker to
-    /// terminate gracefully. The worker waits without consuming any CPU-time
-    /// for the continuation or termination signal of the executor.
+    /// terminate gracefully. The worker is parked without consuming CPU-time
+    /// until it's notified, or the timeout elapses, whichever comes first.
+    /// The timeout bounds the latency of wakeups that were missed, as workers
+    /// are notified without holding the lock, so it's possible that a worker
+    /// misses a notification right before it starts waiting.
     ///
     /// # Errors
     ///
     /// This method returns [`Error::Signal`] if the signal is poisoned.
     pub fn should_terminate(&self) -> Result<bool> {
-        self.mutex
-            .lock()
-            .and_then(|guard| {
-                if *guard {
-                    Ok(guard)
-                } else {
-                    self.value.wait(guard)
-                }
-            })
-            .map_err(|_| Error::Signal)
-            .map(|value| *value)
+        let guard = self.mutex.lock().map_err(|_| Error::Signal)?;
+        if *guard {
+            return Ok(true);
+        }
+
+        // Wait for notification or timeout, then hand back control to the
+        // worker, so it can check for new tasks before waiting again
+        let (guard, _) = self
+            .value
+            .wait_timeout(guard, TIMEOUT)
+            .map_err(|_| Error::Signal)?;
+
+        // Update number of wakeups
+        #[cfg(test)]
+        self.wakeups.fetch_add(1, Ordering::Relaxed);
+        Ok(*guard)
     }
 
     /// Signals the termination of the executor.
@@ -101,4 +124,15 @@ impl Signal {
     pub fn notify(&self) {
         self.value.notify_all();
     }
+
+    /// Returns the number of wakeups.
+    ///
+    /// This method returns how many times waiting workers were woken up, be it
+    /// due to a notification or the timeout, which allows to verify that idle
+    /// workers are parked and don't busy-wait.
+    #[cfg(test)]
+    #[inline]
+    pub fn num_wakeups(&self) -> usize {
+        self.wakeups.load(Ordering::Relaxed)
+    }
 }
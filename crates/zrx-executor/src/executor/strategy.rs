@@ -26,8 +26,12 @@
 //! Execution strategies.
 
 use std::fmt::Debug;
+use std::time::Duration;
+use std::{env, thread};
 
 use super::error::Result;
+use super::idle::Idle;
+use super::panics::{PanicHandler, Panics};
 use super::signal::Signal;
 use super::task::Task;
 
@@ -74,4 +78,132 @@ pub trait Strategy: Debug {
 
     /// Returns the capacity, if bounded.
     fn capacity(&self) -> Option<usize>;
+
+    /// Returns the number of panicked tasks.
+    ///
+    /// The default implementation returns `0`, as strategies that don't catch
+    /// panics of tasks have no means of keeping track of them.
+    #[inline]
+    fn num_tasks_panicked(&self) -> usize {
+        0
+    }
+
+    /// Returns the name of the last panicked task, if any.
+    ///
+    /// Only tasks that are named via [`Task::name`] can be identified, so this
+    /// method returns [`None`] if the last panicked task is unnamed.
+    #[inline]
+    fn last_panicked(&self) -> Option<String> {
+        None
+    }
+
+    /// Sets the panic handler, replacing the previous one, if any.
+    ///
+    /// The handler is invoked with the [`PanicInfo`][] of every panic that is
+    /// caught by the strategy. The default implementation drops the handler,
+    /// as strategies that don't catch panics of tasks can't report them.
+    ///
+    /// [`PanicInfo`]: crate::executor::PanicInfo
+    #[inline]
+    fn set_panic_handler(&self, handler: Box<PanicHandler>) {
+        drop(handler);
+    }
+
+    /// Returns whether there are neither running nor pending tasks.
+    #[inline]
+    fn is_idle(&self) -> bool {
+        self.num_tasks_running() == 0 && self.num_tasks_pending() == 0
+    }
+
+    /// Blocks the current thread until the strategy is idle.
+    ///
+    /// The default implementation polls [`Strategy::is_idle`] in an interval
+    /// of 10ms, which is why strategies that manage worker threads should
+    /// override this method to wake the current thread when becoming idle.
+    fn wait_idle(&self) {
+        let duration = Duration::from_millis(10);
+        while !self.is_idle() {
+            thread::sleep(duration);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns the default number of workers.
+///
+/// If the `ZRX_EXECUTOR_THREADS` environment variable is set to a positive
+/// integer, it's used as the number of workers, which is useful in containers
+/// where [`thread::available_parallelism`] might over-report. Otherwise, the
+/// number of workers is determined by the number of logical CPUs minus one,
+/// which reserves one core for the main thread for orchestration. If the
+/// number of logical CPUs can't be determined or is 1, a single worker is
+/// used.
+///
+/// __Warning__: this function makes use of [`thread::available_parallelism`]
+/// to determine the number of available cores, which has some limitations.
+/// Please refer to the documentation of that function for more details, or
+/// consider using [`num_cpus`][] as an alternative.
+///
+/// [`num_cpus`]: https://crates.io/crates/num_cpus
+///
+/// # Examples
+///
+/// ```
+/// use zrx_executor::strategy::default_worker_count;
+///
+/// // Get default number of workers
+/// assert!(default_worker_count() >= 1);
+/// ```
+#[must_use]
+pub fn default_worker_count() -> usize {
+    let value = env::var("ZRX_EXECUTOR_THREADS").ok();
+    parse_worker_count(value.as_deref())
+}
+
+/// Parses the number of workers, falling back to all CPUs - 1.
+///
+/// Values that are not positive integers are ignored, in which case the number
+/// of logical CPUs minus one is returned, but never less than one worker.
+fn parse_worker_count(value: Option<&str>) -> usize {
+    value
+        .and_then(|value| value.trim().parse::<usize>().ok())
+        .filter(|&num| num > 0)
+        .unwrap_or_else(|| {
+            thread::available_parallelism()
+                .map_or(1, |num| num.get().saturating_sub(1))
+                .max(1)
+        })
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod parse_worker_count {
+        use std::thread;
+
+        use crate::executor::strategy::parse_worker_count;
+
+        #[test]
+        fn handles_valid() {
+            assert_eq!(parse_worker_count(Some("3")), 3);
+            assert_eq!(parse_worker_count(Some(" 3 ")), 3);
+        }
+
+        #[test]
+        fn handles_invalid() {
+            let fallback = thread::available_parallelism()
+                .map_or(1, |num| num.get().saturating_sub(1))
+                .max(1);
+            assert_eq!(parse_worker_count(Some("0")), fallback);
+            assert_eq!(parse_worker_count(Some("invalid")), fallback);
+            assert_eq!(parse_worker_count(None), fallback);
+        }
+    }
 }
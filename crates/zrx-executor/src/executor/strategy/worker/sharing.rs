@@ -25,13 +25,15 @@
 
 //! Work-sharing execution strategy.
 
-use crossbeam::channel::{bounded, Sender};
+use crossbeam::channel::{bounded, unbounded, Receiver, Sender};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
-use std::thread::{self, Builder, JoinHandle};
-use std::{cmp, fmt, panic};
+use std::thread::{Builder, JoinHandle};
+use std::{fmt, panic};
 
-use crate::executor::strategy::Strategy;
+use crate::executor::strategy::{
+    default_worker_count, Idle, PanicHandler, Panics, Strategy,
+};
 use crate::executor::task::Task;
 use crate::executor::Result;
 
@@ -77,6 +79,12 @@ pub struct WorkSharing {
     threads: Vec<JoinHandle<()>>,
     /// Counter for running tasks.
     running: Arc<AtomicUsize>,
+    /// Counter for pending tasks.
+    pending: Arc<AtomicUsize>,
+    /// Notification for becoming idle.
+    idle: Arc<Idle>,
+    /// Record of panicked tasks.
+    panics: Arc<Panics>,
 }
 
 // ----------------------------------------------------------------------------
@@ -137,9 +145,47 @@ impl WorkSharing {
     #[must_use]
     pub fn with_capacity(num_workers: usize, capacity: usize) -> Self {
         let (sender, receiver) = bounded::<Box<dyn Task>>(capacity);
+        Self::from_channel(num_workers, sender, &receiver)
+    }
+
+    /// Creates a work-sharing execution strategy without capacity limits.
+    ///
+    /// This method creates a strategy with the given number of worker threads,
+    /// which are spawned immediately before the method returns. Internally, an
+    /// unbounded channel is created, so task submission never fails due to
+    /// the channel being at capacity, and [`Strategy::capacity`] returns
+    /// [`None`]. This is useful for fire-and-forget workloads, where no
+    /// backpressure should be applied and tasks don't create subtasks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if thread creation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::strategy::{Strategy, WorkSharing};
+    ///
+    /// // Create strategy without capacity limits
+    /// let strategy = WorkSharing::unbounded(4);
+    /// assert_eq!(strategy.capacity(), None);
+    /// ```
+    #[must_use]
+    pub fn unbounded(num_workers: usize) -> Self {
+        let (sender, receiver) = unbounded::<Box<dyn Task>>();
+        Self::from_channel(num_workers, sender, &receiver)
+    }
 
-        // Keep track of running tasks
+    /// Creates a work-sharing execution strategy from the given channel.
+    fn from_channel(
+        num_workers: usize, sender: Sender<Box<dyn Task>>,
+        receiver: &Receiver<Box<dyn Task>>,
+    ) -> Self {
+        // Keep track of running and pending tasks, and notify when idle
         let running = Arc::new(AtomicUsize::new(0));
+        let pending = Arc::new(AtomicUsize::new(0));
+        let idle = Arc::new(Idle::new());
+        let panics = Arc::new(Panics::new());
 
         // Initialize worker threads
         let iter = (0..num_workers).map(|index| {
@@ -150,19 +196,28 @@ impl WorkSharing {
             // track of the number of running tasks to provide a simple way to
             // monitor the load of the thread pool.
             let running = Arc::clone(&running);
+            let pending = Arc::clone(&pending);
+            let idle = Arc::clone(&idle);
+            let panics = Arc::clone(&panics);
             let h = move || {
                 while let Ok(task) = receiver.recv() {
+                    // Update number of running and pending tasks, in this
+                    // order, so the strategy never appears to be idle while
+                    // the task is moved from pending to running
                     running.fetch_add(1, Ordering::Release);
+                    pending.fetch_sub(1, Ordering::Acquire);
 
                     // Execute task and immediately execute all subtasks on the
                     // same worker, if any, as the work-sharing strategy has no
                     // means of distributing work to other workers threads. We
                     // also keep the running count due to sequential execution,
                     // and catch panics, as we're running user-land code that
-                    // might be sloppy. However, since the executor has no way
-                    // of reporting panics, tasks should wrap execution as we
-                    // do here, and abort with a proper error.
-                    let _ = panic::catch_unwind(|| {
+                    // might be sloppy. Panics are recorded together with the
+                    // name of the task, if any, but since the executor has no
+                    // way of propagating them, tasks should wrap execution as
+                    // we do here, and abort with a proper error.
+                    let name = task.name().map(ToOwned::to_owned);
+                    let result = panic::catch_unwind(|| {
                         let subtasks = task.execute();
                         if !subtasks.is_empty() {
                             // Execution is recursive, so in case a subtask has
@@ -170,9 +225,16 @@ impl WorkSharing {
                             subtasks.execute();
                         }
                     });
-
-                    // Update number of running tasks
-                    running.fetch_sub(1, Ordering::Acquire);
+                    if let Err(payload) = result {
+                        panics.record(name, payload.as_ref());
+                    }
+
+                    // Update number of running tasks, and notify waiting
+                    // threads in case this was the last task to finish
+                    let prior = running.fetch_sub(1, Ordering::Acquire);
+                    if prior == 1 && pending.load(Ordering::Acquire) == 0 {
+                        idle.notify();
+                    }
                 }
             };
 
@@ -190,6 +252,9 @@ impl WorkSharing {
             sender: Some(sender),
             threads,
             running,
+            pending,
+            idle,
+            panics,
         }
     }
 }
@@ -217,7 +282,8 @@ impl Strategy for WorkSharing {
     /// # Errors
     ///
     /// If the task cannot be submitted, [`Error::Submit`][] is returned, which
-    /// can only happen if the channel is disconnected or at capacity.
+    /// can only happen if the channel is disconnected or at capacity. Note that
+    /// strategies created with [`WorkSharing::unbounded`] are never at capacity.
     ///
     /// [`Error::Submit`]: crate::executor::Error::Submit
     ///
@@ -235,10 +301,18 @@ impl Strategy for WorkSharing {
     /// # }
     /// ```
     fn submit(&self, task: Box<dyn Task>) -> Result {
-        match self.sender.as_ref() {
-            Some(sender) => Ok(sender.try_send(task)?),
-            None => unreachable!(),
-        }
+        let Some(sender) = self.sender.as_ref() else {
+            unreachable!()
+        };
+
+        // We must count the task as pending before handing it to the channel,
+        // or a worker might receive it before it's counted, which would make
+        // the strategy appear idle while the task is about to be executed
+        self.pending.fetch_add(1, Ordering::Release);
+        sender.try_send(task).map_err(|err| {
+            self.pending.fetch_sub(1, Ordering::Acquire);
+            err.into()
+        })
     }
 
     /// Returns the number of workers.
@@ -292,7 +366,7 @@ impl Strategy for WorkSharing {
     /// ```
     #[inline]
     fn num_tasks_pending(&self) -> usize {
-        self.sender.as_ref().map_or(0, Sender::len)
+        self.pending.load(Ordering::Relaxed)
     }
 
     /// Returns the capacity, if bounded.
@@ -313,6 +387,81 @@ impl Strategy for WorkSharing {
     fn capacity(&self) -> Option<usize> {
         self.sender.as_ref().and_then(Sender::capacity)
     }
+
+    /// Returns the number of panicked tasks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::strategy::{Strategy, WorkSharing};
+    ///
+    /// // Get number of panicked tasks
+    /// let strategy = WorkSharing::default();
+    /// assert_eq!(strategy.num_tasks_panicked(), 0);
+    /// ```
+    #[inline]
+    fn num_tasks_panicked(&self) -> usize {
+        self.panics.count()
+    }
+
+    /// Returns the name of the last panicked task, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::strategy::{Strategy, WorkSharing};
+    ///
+    /// // Get name of last panicked task
+    /// let strategy = WorkSharing::default();
+    /// assert_eq!(strategy.last_panicked(), None);
+    /// ```
+    #[inline]
+    fn last_panicked(&self) -> Option<String> {
+        self.panics.last()
+    }
+
+    /// Sets the panic handler, replacing the previous one, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::strategy::{Strategy, WorkSharing};
+    ///
+    /// // Create strategy and set panic handler
+    /// let strategy = WorkSharing::default();
+    /// strategy.set_panic_handler(Box::new(|info| println!("{info:?}")));
+    /// ```
+    #[inline]
+    fn set_panic_handler(&self, handler: Box<PanicHandler>) {
+        self.panics.set_handler(handler);
+    }
+
+    /// Blocks the current thread until the strategy is idle.
+    ///
+    /// Workers notify waiting threads when finishing the last running task,
+    /// so the current thread is woken up as soon as all tasks are done.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_executor::strategy::{Strategy, WorkSharing};
+    ///
+    /// // Create strategy and submit task
+    /// let strategy = WorkSharing::default();
+    /// strategy.submit(Box::new(|| println!("Task")))?;
+    ///
+    /// // Wait for strategy to become idle
+    /// strategy.wait_idle();
+    /// assert!(strategy.is_idle());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn wait_idle(&self) {
+        self.idle.wait(|| self.is_idle());
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -320,17 +469,10 @@ impl Strategy for WorkSharing {
 impl Default for WorkSharing {
     /// Creates a work-sharing execution strategy using all CPUs - 1.
     ///
-    /// The number of workers is determined by the number of logical CPUs minus
-    /// one, which reserves one core for the main thread for orchestration. If
-    /// the number of logical CPUs is fewer than 1, the strategy defaults to a
-    /// single worker thread.
-    ///
-    /// __Warning__: this method makes use of [`thread::available_parallelism`]
-    /// to determine the number of available cores, which has some limitations.
-    /// Please refer to the documentation of that function for more details, or
-    /// consider using [`num_cpus`][] as an alternative.
-    ///
-    /// [`num_cpus`]: https://crates.io/crates/num_cpus
+    /// The number of workers is determined by [`default_worker_count`], which
+    /// uses the number of logical CPUs minus one, reserving one core for the
+    /// main thread for orchestration, unless overridden through the
+    /// `ZRX_EXECUTOR_THREADS` environment variable.
     ///
     /// # Examples
     ///
@@ -342,12 +484,7 @@ impl Default for WorkSharing {
     /// ```
     #[inline]
     fn default() -> Self {
-        Self::new(cmp::max(
-            thread::available_parallelism()
-                .map(|num| num.get().saturating_sub(1))
-                .unwrap_or(1),
-            1,
-        ))
+        Self::new(default_worker_count())
     }
 }
 
@@ -380,6 +517,83 @@ impl fmt::Debug for WorkSharing {
             .field("workers", &self.num_workers())
             .field("running", &self.num_tasks_running())
             .field("pending", &self.num_tasks_pending())
+            .field("panicked", &self.num_tasks_panicked())
             .finish()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod unbounded {
+        use crate::executor::strategy::{Strategy, WorkSharing};
+        use crate::executor::Result;
+
+        #[test]
+        fn handles_submit() -> Result {
+            let strategy = WorkSharing::unbounded(1);
+            assert_eq!(strategy.capacity(), None);
+            for _ in 0..10_000 {
+                strategy.submit(Box::new(|| {}))?;
+            }
+            Ok(())
+        }
+    }
+
+    mod num_tasks_panicked {
+        use crate::executor::strategy::{Strategy, WorkSharing};
+        use crate::executor::task::NamedTask;
+        use crate::executor::Result;
+
+        fn render() {
+            panic!("render failed");
+        }
+
+        #[test]
+        fn handles_named_task() -> Result {
+            let strategy = WorkSharing::new(1);
+            strategy.submit(Box::new(NamedTask::new("render", render)))?;
+            strategy.wait_idle();
+            assert_eq!(strategy.num_tasks_panicked(), 1);
+            assert_eq!(strategy.last_panicked().as_deref(), Some("render"));
+            Ok(())
+        }
+    }
+
+    mod set_panic_handler {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        use crate::executor::strategy::{Strategy, WorkSharing};
+        use crate::executor::Result;
+
+        fn render() {
+            panic!("render failed");
+        }
+
+        #[test]
+        fn handles_reentrant_handler() -> Result {
+            let strategy = Arc::new(WorkSharing::new(1));
+            let count = Arc::new(AtomicUsize::new(0));
+            let weak = Arc::downgrade(&strategy);
+            let clone = Arc::clone(&count);
+            strategy.set_panic_handler(Box::new(move |_| {
+                if let Some(strategy) = weak.upgrade() {
+                    let clone = Arc::clone(&clone);
+                    strategy.set_panic_handler(Box::new(move |_| {
+                        clone.fetch_add(1, Ordering::Relaxed);
+                    }));
+                }
+            }));
+            strategy.submit(Box::new(render))?;
+            strategy.submit(Box::new(render))?;
+            strategy.wait_idle();
+            assert_eq!(count.load(Ordering::Relaxed), 1);
+            Ok(())
+        }
+    }
+}
@@ -26,14 +26,17 @@
 //! Work-stealing execution strategy.
 
 use crossbeam::deque::{Injector, Steal, Stealer, Worker};
+use std::collections::VecDeque;
 use std::iter::repeat_with;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
-use std::thread::{self, Builder, JoinHandle};
-use std::{cmp, fmt, panic};
+use std::thread::{Builder, JoinHandle};
+use std::{fmt, panic};
 
-use crate::executor::strategy::{Signal, Strategy};
-use crate::executor::task::Task;
+use crate::executor::strategy::{
+    default_worker_count, Idle, PanicHandler, Panics, Signal, Strategy,
+};
+use crate::executor::task::{Task, Tasks};
 use crate::executor::Result;
 
 // ----------------------------------------------------------------------------
@@ -64,6 +67,11 @@ use crate::executor::Result;
 /// as workers can quickly adapt to take on shorter or less complex tasks as
 /// they become available.
 ///
+/// Subtasks that are added via [`Tasks::add_local`][] are never stolen, but
+/// kept in a separate queue that is private to the worker thread producing
+/// them. Local tasks take precedence over all other tasks of the worker.
+///
+/// [`Tasks::add_local`]: crate::executor::task::Tasks::add_local
 /// [`WorkSharing`]: crate::executor::strategy::WorkSharing
 ///
 /// # Examples
@@ -90,6 +98,10 @@ pub struct WorkStealing {
     running: Arc<AtomicUsize>,
     /// Counter for pending tasks.
     pending: Arc<AtomicUsize>,
+    /// Notification for becoming idle.
+    idle: Arc<Idle>,
+    /// Record of panicked tasks.
+    panics: Arc<Panics>,
 }
 
 // ----------------------------------------------------------------------------
@@ -136,9 +148,11 @@ impl WorkStealing {
         let stealers: Arc<[Stealer<Box<dyn Task>>]> =
             Arc::from(workers.iter().map(Worker::stealer).collect::<Vec<_>>());
 
-        // Keep track of running and pending tasks
+        // Keep track of running and pending tasks, and notify when idle
         let running = Arc::new(AtomicUsize::new(0));
         let pending = Arc::new(AtomicUsize::new(0));
+        let idle = Arc::new(Idle::new());
+        let panics = Arc::new(Panics::new());
 
         // Initialize worker threads
         let iter = workers.into_iter().enumerate().map(|(index, worker)| {
@@ -150,16 +164,25 @@ impl WorkStealing {
             // stealers, which we need to retrieve the next task
             let running = Arc::clone(&running);
             let pending = Arc::clone(&pending);
+            let idle = Arc::clone(&idle);
+            let panics = Arc::clone(&panics);
             let h = move || {
                 let injector = injector.as_ref();
                 let stealers = stealers.as_ref();
 
+                // Local tasks must not be stolen, so we keep them in a queue
+                // that is private to the current worker thread
+                let mut local = VecDeque::new();
+
                 // Try to fetch the next task, either from the local queue, or
                 // from the injector or another worker. Additionally, we keep
                 // track of the number of running tasks to provide a simple way
                 // to monitor the load of the thread pool.
                 loop {
-                    let Some(task) = get(&worker, injector, stealers) else {
+                    let opt = local.pop_front();
+                    let Some(task) =
+                        opt.or_else(|| get(&worker, injector, stealers))
+                    else {
                         // No more tasks, so we wait for the executor to signal
                         // if the worker should continue or terminate. This can
                         // fail due to a poisoned lock, in which case we need
@@ -172,32 +195,54 @@ impl WorkStealing {
                         continue;
                     };
 
-                    // Update number of pending and running tasks
-                    pending.fetch_sub(1, Ordering::Acquire);
+                    // Update number of running and pending tasks, in this
+                    // order, so the strategy never appears to be idle while
+                    // the task is moved from pending to running
                     running.fetch_add(1, Ordering::Release);
+                    pending.fetch_sub(1, Ordering::Acquire);
 
-                    // Execute task, but ignore panics, since the executor has
-                    // no way of reporting them, and they're printed anyway
+                    // Execute task, and record panics together with the name
+                    // of the task, if any, since the executor has no way of
+                    // propagating them, and they're printed anyway
+                    let name = task.name().map(ToOwned::to_owned);
                     let subtasks = panic::catch_unwind(|| task.execute())
-                        .unwrap_or_default();
-
-                    // Update number of running tasks
-                    running.fetch_sub(1, Ordering::Acquire);
+                        .unwrap_or_else(|payload| {
+                            panics.record(name, payload.as_ref());
+                            Tasks::new()
+                        });
 
                     // In case the task returned further subtasks, we add them
-                    // to the local queue, so they are executed by the current
+                    // to the worker queue, so they are executed by the current
                     // worker, or can be stolen by another worker in case the
-                    // current worker thread is busy
+                    // current worker thread is busy. Local subtasks are added
+                    // to the private queue, so they can't be stolen.
                     if !subtasks.is_empty() {
-                        let added = subtasks
-                            .into_iter()
-                            .map(|subtask| worker.push(subtask))
-                            .count();
+                        let subtasks = subtasks.into_inner();
+                        let added = subtasks.len();
+                        let mut shared = 0;
+                        for (subtask, is_local) in subtasks {
+                            if is_local {
+                                local.push_back(subtask);
+                            } else {
+                                worker.push(subtask);
+                                shared += 1;
+                            }
+                        }
 
-                        // Update number of running and pending tasks, and wake
-                        // other workers threads to allow for stealing
+                        // Update number of pending tasks, and wake other
+                        // workers threads to allow for stealing, which is
+                        // only necessary if there are any shared subtasks
                         pending.fetch_add(added, Ordering::Release);
-                        signal.notify();
+                        if shared > 0 {
+                            signal.notify();
+                        }
+                    }
+
+                    // Update number of running tasks after adding subtasks,
+                    // and notify waiting threads in case we're now idle
+                    let prior = running.fetch_sub(1, Ordering::Acquire);
+                    if prior == 1 && pending.load(Ordering::Acquire) == 0 {
+                        idle.notify();
                     }
                 }
 
@@ -221,6 +266,8 @@ impl WorkStealing {
             threads,
             running,
             pending,
+            idle,
+            panics,
         }
     }
 }
@@ -349,6 +396,81 @@ impl Strategy for WorkStealing {
     fn capacity(&self) -> Option<usize> {
         None
     }
+
+    /// Returns the number of panicked tasks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::strategy::{Strategy, WorkStealing};
+    ///
+    /// // Get number of panicked tasks
+    /// let strategy = WorkStealing::default();
+    /// assert_eq!(strategy.num_tasks_panicked(), 0);
+    /// ```
+    #[inline]
+    fn num_tasks_panicked(&self) -> usize {
+        self.panics.count()
+    }
+
+    /// Returns the name of the last panicked task, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::strategy::{Strategy, WorkStealing};
+    ///
+    /// // Get name of last panicked task
+    /// let strategy = WorkStealing::default();
+    /// assert_eq!(strategy.last_panicked(), None);
+    /// ```
+    #[inline]
+    fn last_panicked(&self) -> Option<String> {
+        self.panics.last()
+    }
+
+    /// Sets the panic handler, replacing the previous one, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::strategy::{Strategy, WorkStealing};
+    ///
+    /// // Create strategy and set panic handler
+    /// let strategy = WorkStealing::default();
+    /// strategy.set_panic_handler(Box::new(|info| println!("{info:?}")));
+    /// ```
+    #[inline]
+    fn set_panic_handler(&self, handler: Box<PanicHandler>) {
+        self.panics.set_handler(handler);
+    }
+
+    /// Blocks the current thread until the strategy is idle.
+    ///
+    /// Workers notify waiting threads when finishing the last running task,
+    /// so the current thread is woken up as soon as all tasks are done.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_executor::strategy::{Strategy, WorkStealing};
+    ///
+    /// // Create strategy and submit task
+    /// let strategy = WorkStealing::default();
+    /// strategy.submit(Box::new(|| println!("Task")))?;
+    ///
+    /// // Wait for strategy to become idle
+    /// strategy.wait_idle();
+    /// assert!(strategy.is_idle());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn wait_idle(&self) {
+        self.idle.wait(|| self.is_idle());
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -356,17 +478,10 @@ impl Strategy for WorkStealing {
 impl Default for WorkStealing {
     /// Creates a work-stealing execution strategy using all CPUs - 1.
     ///
-    /// The number of workers is determined by the number of logical CPUs minus
-    /// one, which reserves one core for the main thread for orchestration. If
-    /// the number of logical CPUs is fewer than 1, the strategy defaults to a
-    /// single worker thread.
-    ///
-    /// __Warning__: this method makes use of [`thread::available_parallelism`]
-    /// to determine the number of available cores, which has some limitations.
-    /// Please refer to the documentation of that function for more details, or
-    /// consider using [`num_cpus`][] as an alternative.
-    ///
-    /// [`num_cpus`]: https://crates.io/crates/num_cpus
+    /// The number of workers is determined by [`default_worker_count`], which
+    /// uses the number of logical CPUs minus one, reserving one core for the
+    /// main thread for orchestration, unless overridden through the
+    /// `ZRX_EXECUTOR_THREADS` environment variable.
     ///
     /// # Examples
     ///
@@ -378,12 +493,7 @@ impl Default for WorkStealing {
     /// ```
     #[inline]
     fn default() -> Self {
-        Self::new(cmp::max(
-            thread::available_parallelism()
-                .map(|num| num.get().saturating_sub(1))
-                .unwrap_or(1),
-            1,
-        ))
+        Self::new(default_worker_count())
     }
 }
 
@@ -413,6 +523,7 @@ impl fmt::Debug for WorkStealing {
             .field("workers", &self.num_workers())
             .field("running", &self.num_tasks_running())
             .field("pending", &self.num_tasks_pending())
+            .field("panicked", &self.num_tasks_panicked())
             .finish()
     }
 }
@@ -452,3 +563,79 @@ fn steal<T>(
         .steal_batch_and_pop(worker)
         .or_else(|| stealers.iter().map(Stealer::steal).collect())
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod new {
+        use std::thread;
+        use std::time::Duration;
+
+        use crate::executor::strategy::WorkStealing;
+
+        #[test]
+        fn handles_idle_workers() {
+            let strategy = WorkStealing::new(2);
+            thread::sleep(Duration::from_millis(250));
+
+            // Idle workers are parked, waking up only due to the timeout
+            assert!(strategy.signal.num_wakeups() <= 10);
+        }
+    }
+
+    mod submit {
+        use std::sync::{Arc, Mutex};
+        use std::thread::{self, ThreadId};
+        use std::time::Duration;
+
+        use crate::executor::strategy::{Strategy, WorkStealing};
+        use crate::executor::task::Tasks;
+
+        fn chain(n: usize, ids: Arc<Mutex<Vec<ThreadId>>>) -> Tasks {
+            ids.lock().unwrap().push(thread::current().id());
+            let mut tasks = Tasks::new();
+            if n > 0 {
+                // Add a shared subtask, so idle workers try to steal
+                tasks.add(|| thread::sleep(Duration::from_millis(1)));
+                tasks.add_local(move || chain(n - 1, ids));
+            }
+            tasks
+        }
+
+        #[test]
+        fn handles_local_subtasks() {
+            let strategy = WorkStealing::new(4);
+            let ids = Arc::new(Mutex::new(Vec::new()));
+            let clone = Arc::clone(&ids);
+            strategy.submit(Box::new(move || chain(20, clone))).unwrap();
+            strategy.wait_idle();
+            let ids = ids.lock().unwrap();
+            assert_eq!(ids.len(), 21);
+            assert!(ids.iter().all(|id| *id == ids[0]));
+        }
+    }
+
+    mod num_tasks_panicked {
+        use crate::executor::strategy::{Strategy, WorkStealing};
+        use crate::executor::task::NamedTask;
+        use crate::executor::Result;
+
+        fn render() {
+            panic!("render failed");
+        }
+
+        #[test]
+        fn handles_named_task() -> Result {
+            let strategy = WorkStealing::new(1);
+            strategy.submit(Box::new(NamedTask::new("render", render)))?;
+            strategy.wait_idle();
+            assert_eq!(strategy.num_tasks_panicked(), 1);
+            assert_eq!(strategy.last_panicked().as_deref(), Some("render"));
+            Ok(())
+        }
+    }
+}
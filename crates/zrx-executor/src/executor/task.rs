@@ -29,8 +29,10 @@ use std::fmt;
 use std::panic::UnwindSafe;
 
 mod collection;
+mod named;
 
 pub use collection::Tasks;
+pub use named::NamedTask;
 
 // ----------------------------------------------------------------------------
 // Traits
@@ -58,6 +60,16 @@ pub trait Task: Send + UnwindSafe + 'static {
     /// As task execution must be infallible, tasks might use channels in order
     /// to communicate results or errors back to the main thread.
     fn execute(self: Box<Self>) -> Tasks;
+
+    /// Returns the name of the task, if any.
+    ///
+    /// Names are included in panic reports of the worker execution strategies,
+    /// which is why tasks should be named with [`NamedTask`] when they need to
+    /// be identifiable. In the default implementation, tasks are unnamed.
+    #[inline]
+    fn name(&self) -> Option<&str> {
+        None
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -25,6 +25,7 @@
 
 //! Task collection.
 
+use std::iter::Zip;
 use std::vec::IntoIter;
 
 use super::Task;
@@ -67,6 +68,8 @@ use super::Task;
 pub struct Tasks {
     /// Vector of tasks.
     inner: Vec<Box<dyn Task>>,
+    /// Whether tasks are local, by position.
+    local: Vec<bool>,
 }
 
 // ----------------------------------------------------------------------------
@@ -112,9 +115,52 @@ impl Tasks {
         T: Task,
     {
         self.inner.push(Box::new(task));
+        self.local.push(false);
         self
     }
 
+    /// Adds a local task to the task collection.
+    ///
+    /// Local tasks must be executed on the same worker thread as the task that
+    /// returned them, e.g., since they depend on thread-local state, or for
+    /// better cache locality. Execution strategies that support stealing, like
+    /// [`WorkStealing`][], guarantee local tasks are never stolen. All other
+    /// strategies treat local tasks like any other task.
+    ///
+    /// [`WorkStealing`]: crate::executor::strategy::WorkStealing
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::task::Tasks;
+    ///
+    /// // Create task collection and add local tasks
+    /// let mut tasks = Tasks::new();
+    /// tasks.add_local(|| println!("Task 1"));
+    /// tasks.add_local(|| println!("Task 2"));
+    /// tasks.add_local(|| println!("Task 3"));
+    /// ```
+    #[inline]
+    pub fn add_local<T>(&mut self, task: T) -> &mut Self
+    where
+        T: Task,
+    {
+        self.inner.push(Box::new(task));
+        self.local.push(true);
+        self
+    }
+
+    /// Consumes the task collection, returning tasks and whether they're local.
+    ///
+    /// This method is used by execution strategies which support stealing, in
+    /// order to keep local tasks on the worker thread that produced them.
+    #[inline]
+    pub(crate) fn into_inner(
+        self,
+    ) -> Zip<IntoIter<Box<dyn Task>>, IntoIter<bool>> {
+        self.inner.into_iter().zip(self.local)
+    }
+
     /// Executes all tasks in the task collection.
     ///
     /// This method executes all tasks recursively in depth-first order, so if
@@ -141,7 +187,7 @@ impl Tasks {
         while let Some(task) = self.inner.pop() {
             // Execute the current task, and if it returns further subtasks,
             // push them onto the stack in reverse order
-            self.inner.extend(task.execute().into_iter().rev());
+            self.inner.extend(task.execute().inner.into_iter().rev());
         }
     }
 }
@@ -0,0 +1,117 @@
+// Copyright (c) 2025-2026 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// All contributions are certified under the DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Named task.
+
+use std::fmt;
+
+use super::{Task, Tasks};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Named task.
+///
+/// This data type wraps a task and associates it with a human-readable name,
+/// which is returned by [`Task::name`], and included in panic reports of the
+/// worker execution strategies, so it's easier to identify failing tasks.
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_executor::task::NamedTask;
+/// use zrx_executor::Executor;
+///
+/// // Create executor and submit named task
+/// let executor = Executor::default();
+/// executor.submit(NamedTask::new("render", || println!("Task")))?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct NamedTask {
+    /// Name of the task.
+    name: String,
+    /// Wrapped task.
+    task: Box<dyn Task>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl NamedTask {
+    /// Creates a named task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::task::NamedTask;
+    ///
+    /// // Create named task
+    /// let task = NamedTask::new("render", || println!("Task"));
+    /// ```
+    pub fn new<N, T>(name: N, task: T) -> Self
+    where
+        N: Into<String>,
+        T: Task,
+    {
+        Self {
+            name: name.into(),
+            task: Box::new(task),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl Task for NamedTask {
+    /// Executes the wrapped task.
+    #[inline]
+    fn execute(self: Box<Self>) -> Tasks {
+        self.task.execute()
+    }
+
+    /// Returns the name of the task.
+    #[inline]
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl fmt::Debug for NamedTask {
+    /// Formats the named task for debugging.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NamedTask")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
@@ -28,5 +28,7 @@
 mod executor;
 
 pub use executor::strategy::{self, Strategy};
-pub use executor::task::{self, Task, Tasks};
-pub use executor::{Error, Executor, Result};
+pub use executor::task::{self, NamedTask, Task, Tasks};
+pub use executor::{
+    Error, Executor, ExecutorMetrics, PanicHandler, PanicInfo, Result, Scope,
+};
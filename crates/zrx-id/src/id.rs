@@ -33,6 +33,7 @@ use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
 
+use zrx_path::transform::{normalize, relative_to, strip_prefix_normalized};
 use zrx_path::PathExt;
 
 mod builder;
@@ -41,13 +42,19 @@ mod error;
 pub mod filter;
 pub mod format;
 mod macros;
+mod mask;
 pub mod matcher;
+mod parts;
+mod relative;
 pub mod uri;
 
 pub use builder::Builder;
 pub use convert::TryIntoId;
 pub use error::{Error, Result};
 use format::Format;
+pub use mask::ComponentMask;
+pub use parts::IdParts;
+pub use relative::ContextRelative;
 use uri::Uri;
 
 // ----------------------------------------------------------------------------
@@ -215,6 +222,231 @@ impl Id {
     pub fn as_uri(&self) -> Uri<'_> {
         Uri::from(self.location())
     }
+
+    /// Returns the relative URI from the identifier to the given identifier.
+    ///
+    /// This method treats the `location` of the identifier as the base, which
+    /// is considered to be a file, unless it ends with a slash, and computes
+    /// the relative URI to the `location` of the target, e.g., to construct
+    /// relative links between pages. If both identifiers are identical, the
+    /// relative URI is the last component of the location, i.e., its file.
+    ///
+    /// Relative URIs can only be computed within the same `context`, as there
+    /// are no guarantees about the relation of different contexts. Thus, if the
+    /// contexts differ, the URI of the target's `location` is returned as is,
+    /// which is relative to the root of the target's `context`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::uri::Uri;
+    /// use zrx_id::Id;
+    ///
+    /// // Create identifiers from strings
+    /// let a: Id = "zri:file:::docs:guide/index.md:".parse()?;
+    /// let b: Id = "zri:file:::docs:about.md:".parse()?;
+    ///
+    /// // Obtain relative URI from one identifier to another
+    /// assert_eq!(a.relative_uri_to(&b), Uri::from("../about.md"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn relative_uri_to(&self, target: &Id) -> Uri<'static> {
+        if self.context() != target.context() {
+            return Uri::from(target.location().into_owned());
+        }
+
+        // Compute relative path from base location to target location
+        let base = self.location();
+        let path = relative_to(target.location().as_ref(), base.as_ref());
+        Uri::from(path.to_string_lossy().replace('\\', "/"))
+    }
+
+    /// Returns all components in order.
+    ///
+    /// This method returns the `provider`, `resource`, `variant`, `context`,
+    /// `location` and `fragment` components, so they can be destructured in a
+    /// single call. Optional components that are not set are represented as
+    /// empty strings, in contrast to the respective accessors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Id;
+    ///
+    /// // Create identifier from string
+    /// let id: Id = "zri:file:::docs:index.md:".parse()?;
+    ///
+    /// // Obtain and destructure components
+    /// let [provider, _, _, context, location, _] = id.components();
+    /// assert_eq!(provider, "file");
+    /// assert_eq!(context, "docs");
+    /// assert_eq!(location, "index.md");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn components(&self) -> [Cow<'_, str>; 6] {
+        [1, 2, 3, 4, 5, 6].map(|index| self.format.get(index))
+    }
+
+    /// Returns whether the identifier is a prefix of the given identifier.
+    ///
+    /// This method checks whether all components except for `location` are
+    /// equal, and the `location` is an ancestor of the other identifier's
+    /// `location` after normalizing both. Thus, identifiers pointing to a
+    /// folder are prefixes of all identifiers pointing to files beneath it,
+    /// and every identifier is a prefix of itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Id;
+    ///
+    /// // Create identifiers from strings
+    /// let a: Id = "zri:file:::docs:guide/:".parse()?;
+    /// let b: Id = "zri:file:::docs:guide/index.md:".parse()?;
+    ///
+    /// // Check whether identifier is a prefix
+    /// assert!(a.is_prefix_of(&b));
+    /// assert!(!b.is_prefix_of(&a));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn is_prefix_of(&self, other: &Id) -> bool {
+        let a = self.components();
+        let b = other.components();
+
+        // Ensure all components except for the location are equal, and check
+        // whether the location is an ancestor of the other location
+        let mut iter = a.iter().zip(&b).enumerate();
+        iter.all(|(index, (x, y))| index == 4 || x == y)
+            && strip_prefix_normalized(b[4].as_ref(), a[4].as_ref()).is_some()
+    }
+
+    /// Returns the canonical form of the identifier.
+    ///
+    /// This method normalizes the `context` and `location` components, and
+    /// rebuilds the identifier, so it compares equal to all identifiers that
+    /// refer to the same artifact. Since path traversals are rejected when an
+    /// identifier is created, this consolidates `.` components and redundant
+    /// slashes. Trailing slashes are preserved.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Component`] if the `context` or `location`
+    /// component is empty after normalization, e.g., when it's just `.`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Id;
+    ///
+    /// // Create identifier from string
+    /// let id: Id = "zri:file:::docs:./guide//index.md:".parse()?;
+    ///
+    /// // Obtain canonical form of identifier
+    /// let id = id.canonicalize()?;
+    /// assert_eq!(id.as_str(), "zri:file:::docs:guide/index.md:");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn canonicalize(&self) -> Result<Self> {
+        let context = canonicalize(self.context().as_ref());
+        let location = canonicalize(self.location().as_ref());
+        self.to_builder()
+            .with_context(context)
+            .with_location(location)
+            .build()
+    }
+
+    /// Returns the identifier with the given fragment joined onto it.
+    ///
+    /// This method rebuilds the identifier with the given `fragment`, but in
+    /// contrast to setting it via the [`Builder`], it never overwrites an
+    /// existing fragment, which makes fragment composition explicit.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Component`] if the identifier already has
+    /// a `fragment` component, or if the given fragment is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Id;
+    ///
+    /// // Create identifier from string
+    /// let id: Id = "zri:file:::docs:index.md:".parse()?;
+    ///
+    /// // Join fragment onto identifier
+    /// let id = id.join_fragment("anchor")?;
+    /// assert_eq!(id.as_str(), "zri:file:::docs:index.md:anchor");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn join_fragment(&self, fragment: &str) -> Result<Self> {
+        if fragment.is_empty() || self.fragment().is_some() {
+            return Err(Error::Component("fragment"));
+        }
+
+        // Rebuild identifier with fragment
+        self.to_builder().with_fragment(fragment).build()
+    }
+
+    /// Compares two identifiers by their components in order of priority.
+    ///
+    /// In contrast to [`Ord`], which compares the formatted strings, and thus
+    /// groups identifiers by their leading components, this method compares
+    /// the `provider`, `context` and `location` components first, and then
+    /// the `resource`, `variant` and `fragment` components. This yields more
+    /// intuitive orderings, e.g., for file trees, and can be passed to sorting
+    /// methods like [`slice::sort_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Id;
+    ///
+    /// // Create identifiers from strings
+    /// let mut ids: Vec<Id> = vec![
+    ///     "zri:file:::docs:b.md:".parse()?,
+    ///     "zri:file:main::docs:a.md:".parse()?,
+    /// ];
+    ///
+    /// // Sort identifiers by components
+    /// ids.sort_by(Id::cmp_by_components);
+    /// assert_eq!(ids[0].location(), "a.md");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn cmp_by_components(&self, other: &Id) -> Ordering {
+        let a = self.components();
+        let b = other.components();
+
+        // Compare components in order of priority, short-circuiting as soon
+        // as the first component differs
+        let iter = [0, 3, 4, 1, 2, 5].into_iter();
+        iter.map(|index| a[index].cmp(&b[index]))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
 }
 
 #[allow(clippy::must_use_candidate)]
@@ -254,6 +486,33 @@ impl Id {
     pub fn fragment(&self) -> Option<Cow<'_, str>> {
         Some(self.format.get(6)).filter(|value| !value.is_empty())
     }
+
+    /// Returns whether the `resource` component is set.
+    #[inline]
+    pub fn has_resource(&self) -> bool {
+        !self.format.get(2).is_empty()
+    }
+
+    /// Returns whether the `variant` component is set.
+    #[inline]
+    pub fn has_variant(&self) -> bool {
+        !self.format.get(3).is_empty()
+    }
+
+    /// Returns whether the `fragment` component is set.
+    #[inline]
+    pub fn has_fragment(&self) -> bool {
+        !self.format.get(6).is_empty()
+    }
+
+    /// Returns the number of set components, which is at least three, as the
+    /// `provider`, `context` and `location` components are always set.
+    #[inline]
+    pub fn num_set_components(&self) -> usize {
+        let optional =
+            [self.has_resource(), self.has_variant(), self.has_fragment()];
+        3 + optional.into_iter().filter(|&set| set).count()
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -449,3 +708,344 @@ impl fmt::Debug for Id {
             .finish()
     }
 }
+
+// ----------------------------------------------------------------------------
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Id {
+    /// Generates an arbitrary identifier.
+    ///
+    /// All generated components are valid, i.e., they only consist of ASCII
+    /// alphanumeric characters, dashes and underscores, and `context` and
+    /// `location` are made of one or more of those segments separated by
+    /// slashes, so backslashes and path traversals never occur. The required
+    /// components `provider`, `context` and `location` are always non-empty.
+    fn arbitrary(
+        u: &mut arbitrary::Unstructured<'a>,
+    ) -> arbitrary::Result<Self> {
+        let mut builder = Id::builder();
+        builder.set_provider(arbitrary_segment(u)?);
+        if u.arbitrary()? {
+            builder.set_resource(arbitrary_segment(u)?);
+        }
+        if u.arbitrary()? {
+            builder.set_variant(arbitrary_segment(u)?);
+        }
+        builder.set_context(arbitrary_path(u)?);
+        builder.set_location(arbitrary_path(u)?);
+        if u.arbitrary()? {
+            builder.set_fragment(arbitrary_segment(u)?);
+        }
+
+        // Building can't fail, as all components are valid
+        builder
+            .build()
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Normalizes the given path component, ensuring forward slashes.
+fn canonicalize(value: &str) -> String {
+    normalize(value).to_string_lossy().replace('\\', "/")
+}
+
+/// Generates an arbitrary non-empty component segment.
+#[cfg(feature = "arbitrary")]
+fn arbitrary_segment(
+    u: &mut arbitrary::Unstructured<'_>,
+) -> arbitrary::Result<String> {
+    const ALPHABET: &[u8] =
+        b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789-_";
+    (0..u.int_in_range(1..=8)?)
+        .map(|_| u.choose(ALPHABET).map(|&char| char::from(char)))
+        .collect()
+}
+
+/// Generates an arbitrary non-empty path of component segments.
+#[cfg(feature = "arbitrary")]
+fn arbitrary_path(
+    u: &mut arbitrary::Unstructured<'_>,
+) -> arbitrary::Result<String> {
+    let segments = (0..u.int_in_range(1..=3)?)
+        .map(|_| arbitrary_segment(u))
+        .collect::<arbitrary::Result<Vec<_>>>()?;
+    Ok(segments.join("/"))
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod relative_uri_to {
+        use crate::id::uri::Uri;
+        use crate::id::{Id, Result};
+
+        #[test]
+        fn handles_sibling() -> Result {
+            let a: Id = "zri:file:::docs:guide/a.md:".parse()?;
+            let b: Id = "zri:file:::docs:guide/b.md:".parse()?;
+            assert_eq!(a.relative_uri_to(&b), Uri::from("b.md"));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_nested() -> Result {
+            let a: Id = "zri:file:::docs:index.md:".parse()?;
+            let b: Id = "zri:file:::docs:guide/setup/index.md:".parse()?;
+            assert_eq!(
+                a.relative_uri_to(&b),
+                Uri::from("guide/setup/index.md")
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_parent() -> Result {
+            let a: Id = "zri:file:::docs:guide/setup/index.md:".parse()?;
+            let b: Id = "zri:file:::docs:index.md:".parse()?;
+            assert_eq!(a.relative_uri_to(&b), Uri::from("../../index.md"));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_identical() -> Result {
+            let a: Id = "zri:file:::docs:guide/index.md:".parse()?;
+            assert_eq!(a.relative_uri_to(&a), Uri::from("index.md"));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_different_context() -> Result {
+            let a: Id = "zri:file:::docs:guide/index.md:".parse()?;
+            let b: Id = "zri:file:::blog:posts/index.md:".parse()?;
+            assert_eq!(a.relative_uri_to(&b), Uri::from("posts/index.md"));
+            Ok(())
+        }
+    }
+
+    mod components {
+        use crate::id::{Id, Result};
+
+        #[test]
+        fn handles_id() -> Result {
+            let id: Id = "zri:git:master:en:docs:index.md:anchor".parse()?;
+            assert_eq!(
+                id.components(),
+                [
+                    id.provider(),
+                    id.resource().unwrap_or_default(),
+                    id.variant().unwrap_or_default(),
+                    id.context(),
+                    id.location(),
+                    id.fragment().unwrap_or_default(),
+                ]
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_optionals() -> Result {
+            let id: Id = "zri:file:::docs:index.md:".parse()?;
+            assert_eq!(
+                id.components(),
+                ["file", "", "", "docs", "index.md", ""]
+            );
+            Ok(())
+        }
+    }
+
+    mod is_prefix_of {
+        use crate::id::{Id, Result};
+
+        #[test]
+        fn handles_folder() -> Result {
+            let a: Id = "zri:file:::docs:guide/:".parse()?;
+            let b: Id = "zri:file:::docs:guide/index.md:".parse()?;
+            assert!(a.is_prefix_of(&b));
+            assert!(!b.is_prefix_of(&a));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_identical() -> Result {
+            let a: Id = "zri:file:::docs:guide/index.md:".parse()?;
+            assert!(a.is_prefix_of(&a));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_different_context() -> Result {
+            let a: Id = "zri:file:::docs:guide/:".parse()?;
+            let b: Id = "zri:file:::blog:guide/index.md:".parse()?;
+            assert!(!a.is_prefix_of(&b));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_unnormalized() -> Result {
+            let a: Id = "zri:file:::docs:guide:".parse()?;
+            let b: Id = "zri:file:::docs:guide/./index.md:".parse()?;
+            assert!(a.is_prefix_of(&b));
+            Ok(())
+        }
+    }
+
+    mod canonicalize {
+        use crate::id::{Id, Result};
+
+        #[test]
+        fn handles_current_dir() -> Result {
+            let a: Id = "zri:file:::docs:./guide/./index.md:".parse()?;
+            let b: Id = "zri:file:::docs:guide/index.md:".parse()?;
+            assert_ne!(a, b);
+            assert_eq!(a.canonicalize()?, b.canonicalize()?);
+            assert_eq!(a.canonicalize()?, b);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_redundant_slashes() -> Result {
+            let a: Id = "zri:file:::docs//api:guide//index.md:".parse()?;
+            let b: Id = "zri:file:::docs/api:guide/index.md:".parse()?;
+            assert_eq!(a.canonicalize()?, b);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_trailing_slash() -> Result {
+            let a: Id = "zri:file:::docs:guide//:".parse()?;
+            let b: Id = "zri:file:::docs:guide/:".parse()?;
+            assert_eq!(a.canonicalize()?, b);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_empty() -> Result {
+            let id: Id = "zri:file:::docs:.:".parse()?;
+            assert!(id.canonicalize().is_err());
+            Ok(())
+        }
+    }
+
+    mod join_fragment {
+        use crate::id::{Error, Id, Result};
+
+        #[test]
+        fn handles_id() -> Result {
+            let id: Id = "zri:file:::docs:index.md:".parse()?;
+            let id = id.join_fragment("anchor")?;
+            assert_eq!(id.fragment().as_deref(), Some("anchor"));
+            assert_eq!(id.as_str(), "zri:file:::docs:index.md:anchor");
+            Ok(())
+        }
+
+        #[test]
+        fn handles_existing() -> Result {
+            let id: Id = "zri:file:::docs:index.md:anchor".parse()?;
+            assert!(matches!(
+                id.join_fragment("other"),
+                Err(Error::Component("fragment"))
+            ));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_empty() -> Result {
+            let id: Id = "zri:file:::docs:index.md:".parse()?;
+            assert!(matches!(
+                id.join_fragment(""),
+                Err(Error::Component("fragment"))
+            ));
+            Ok(())
+        }
+    }
+
+    mod cmp_by_components {
+        use crate::id::{Id, Result};
+
+        #[test]
+        fn handles_ids() -> Result {
+            let mut ids: Vec<Id> = Vec::from([
+                "zri:git:main::docs:a.md:".parse()?,
+                "zri:file:main::docs:b.md:".parse()?,
+                "zri:file:::docs:c.md:".parse()?,
+                "zri:file:::blog:a.md:".parse()?,
+                "zri:file:main::docs:a.md:".parse()?,
+            ]);
+            let mut check = ids.clone();
+            check.sort();
+            ids.sort_by(Id::cmp_by_components);
+            assert_ne!(ids, check);
+            assert_eq!(
+                ids.iter().map(Id::as_str).collect::<Vec<_>>(),
+                [
+                    "zri:file:::blog:a.md:",
+                    "zri:file:main::docs:a.md:",
+                    "zri:file:main::docs:b.md:",
+                    "zri:file:::docs:c.md:",
+                    "zri:git:main::docs:a.md:",
+                ]
+            );
+            Ok(())
+        }
+    }
+
+    mod num_set_components {
+        use crate::id::{Id, Result};
+
+        #[test]
+        fn handles_required() -> Result {
+            let id: Id = "zri:file:::docs:index.md:".parse()?;
+            assert!(!id.has_resource());
+            assert!(!id.has_variant());
+            assert!(!id.has_fragment());
+            assert_eq!(id.num_set_components(), 3);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_optional() -> Result {
+            let id: Id = "zri:git:main:en:docs:index.md:anchor".parse()?;
+            assert!(id.has_resource());
+            assert!(id.has_variant());
+            assert!(id.has_fragment());
+            assert_eq!(id.num_set_components(), 6);
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "arbitrary")]
+    mod arbitrary {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        use crate::id::{Id, Result};
+
+        #[test]
+        fn handles_round_trip() -> Result {
+            let mut state = 0x2545_f491_4f6c_dd1d_u64;
+            let bytes = (0..4096)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    state.to_le_bytes()[0]
+                })
+                .collect::<Vec<_>>();
+            let mut u = Unstructured::new(&bytes);
+            for _ in 0..100 {
+                let id = Id::arbitrary(&mut u).unwrap();
+                let parsed: Id = id.as_str().parse()?;
+                assert_eq!(parsed.as_str(), id.as_str());
+                assert_eq!(parsed, id);
+            }
+            Ok(())
+        }
+    }
+}
@@ -31,6 +31,7 @@ use std::sync::Arc;
 
 use super::error::{Error, Result};
 use super::format::{self, Format};
+use super::mask::ComponentMask;
 use super::Id;
 
 // ----------------------------------------------------------------------------
@@ -344,6 +345,47 @@ impl<'a> Builder<'a> {
         self
     }
 
+    /// Updates the components selected by the mask from the given identifier.
+    ///
+    /// This method copies all components selected by [`ComponentMask`] from
+    /// the other identifier, which allows to derive an identifier from another
+    /// one, e.g., to keep the `provider` and `location` of a source identifier
+    /// while setting a different `context` for the output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::{ComponentMask, Id};
+    ///
+    /// // Create identifier from string
+    /// let id: Id = "zri:file:::docs:index.md:".parse()?;
+    ///
+    /// // Create identifier builder and inherit components
+    /// let mut builder = Id::builder();
+    /// let mask = ComponentMask::PROVIDER | ComponentMask::LOCATION;
+    /// builder.inherit_from(&id, mask);
+    /// builder.set_context("site");
+    ///
+    /// // Create identifier from builder
+    /// let id = builder.build()?;
+    /// assert_eq!(id.as_str(), "zri:file:::site:index.md:");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn inherit_from(
+        &mut self, other: &'a Id, mask: ComponentMask,
+    ) -> &mut Self {
+        for index in 1..=6 {
+            if mask.has_index(index) {
+                self.format.set(index, other.format.get(index));
+            }
+        }
+        self
+    }
+
     /// Builds the identifier.
     ///
     /// # Errors
@@ -400,3 +442,51 @@ impl<'a> Builder<'a> {
         Ok(Id { format: Arc::new(format), hash })
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod inherit_from {
+        use crate::id::{ComponentMask, Id, Result};
+
+        #[test]
+        fn handles_mask() -> Result {
+            let id: Id = "zri:file:master:en:docs:index.md:anchor".parse()?;
+            let mut builder = Id::builder();
+            builder.inherit_from(
+                &id,
+                ComponentMask::PROVIDER | ComponentMask::LOCATION,
+            );
+            builder.set_context("site");
+            assert_eq!(builder.build()?.as_str(), "zri:file:::site:index.md:");
+            Ok(())
+        }
+
+        #[test]
+        fn handles_all() -> Result {
+            let id: Id = "zri:file:master:en:docs:index.md:anchor".parse()?;
+            let mut builder = Id::builder();
+            builder.inherit_from(&id, ComponentMask::ALL);
+            builder.set_context("site");
+            assert_eq!(
+                builder.build()?.as_str(),
+                "zri:file:master:en:site:index.md:anchor"
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_override() -> Result {
+            let id: Id = "zri:file:::docs:index.md:".parse()?;
+            let mut builder = id.to_builder();
+            builder.set_context("site");
+            builder.inherit_from(&id, ComponentMask::NONE);
+            assert_eq!(builder.build()?.as_str(), "zri:file:::site:index.md:");
+            Ok(())
+        }
+    }
+}
@@ -34,6 +34,7 @@ mod candidates;
 mod condition;
 mod error;
 pub mod expression;
+mod set;
 mod terms;
 
 pub use builder::Builder;
@@ -41,6 +42,7 @@ pub use candidates::Candidates;
 use condition::Condition;
 pub use error::{Error, Result};
 pub use expression::{Expression, IntoExpression, Term};
+pub use set::FilterSet;
 pub use terms::Terms;
 
 // ----------------------------------------------------------------------------
@@ -92,6 +92,17 @@ impl Filter {
     pub fn into_builder(self) -> Builder {
         Builder { conditions: self.conditions }
     }
+
+    /// Creates a filter builder from a copy of the conditions of the filter.
+    ///
+    /// This allows to build a modified filter without giving up the current
+    /// one, so it can be retained in case building the modified filter fails.
+    #[inline]
+    pub(super) fn to_builder(&self) -> Builder {
+        Builder {
+            conditions: self.conditions.clone(),
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -45,7 +45,7 @@ pub use instruction::Instruction;
 /// notation - also known as reverse polish notation (RPN) - for very efficient
 /// and fast matching against a set of extracted terms. Conditions are an
 /// internal construct and not exported via the public interface.
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Condition {
     /// Instructions in postfix notation.
     instructions: Box<[Instruction]>,
@@ -110,6 +110,73 @@ impl Condition {
         // representing the result of the entire condition evaluation
         stack == 1
     }
+
+    /// Returns the terms that contributed to satisfying the condition.
+    ///
+    /// This method evaluates the underlying instructions like [`satisfies`][],
+    /// but additionally keeps track of the indices of all matched terms that
+    /// contributed to a satisfied result. Operands of the logical `NOT`
+    /// operator never contribute, since they're satisfied by the absence of
+    /// matches. If the condition is not satisfied, the returned set is empty.
+    ///
+    /// [`satisfies`]: Condition::satisfies
+    #[allow(clippy::missing_panics_doc)]
+    #[must_use]
+    pub fn satisfied_terms(&self, matches: &Matches) -> Matches {
+        let capacity = self.terms.len();
+        let mut stack = Vec::<(bool, Matches)>::new();
+
+        // Evaluate instructions in postfix notation, tracking contributions
+        // alongside the result of each instruction
+        for instruction in &self.instructions {
+            match instruction {
+                // Compare terms against matches, and record the matched terms
+                // as contributing, unless the operator is a negation
+                Instruction::Compare(operator, terms) => {
+                    let mut contributed = terms.clone();
+                    contributed.intersect(matches);
+                    stack.push(match operator {
+                        Operator::Any => (terms.has_any(matches), contributed),
+                        Operator::All => (terms.has_all(matches), contributed),
+                        Operator::Not => {
+                            (terms.has_any(matches), Matches::new())
+                        }
+                    });
+                }
+                // Combine prior results according to the operator semantics,
+                // merging the contributions of all satisfied operands
+                Instruction::Combine(operator, arity) => {
+                    let operands = stack.split_off(stack.len() - arity);
+                    let mut iter = operands.iter().map(|(check, _)| *check);
+                    let satisfied = match operator {
+                        Operator::Any => iter.any(|check| check),
+                        Operator::All => iter.all(|check| check),
+                        Operator::Not => !iter.any(|check| check),
+                    };
+
+                    // Negations never contribute, as they represent absence
+                    let mut contributed = Matches::with_capacity(capacity);
+                    if satisfied && *operator != Operator::Not {
+                        for (check, terms) in &operands {
+                            if *check {
+                                contributed.union(terms);
+                            }
+                        }
+                    }
+                    stack.push((satisfied, contributed));
+                }
+            }
+        }
+
+        // At the end, there must be exactly one value left on the stack,
+        // representing the result and contributions of the entire condition
+        let (satisfied, contributed) = stack.pop().expect("invariant");
+        if satisfied {
+            contributed
+        } else {
+            Matches::with_capacity(capacity)
+        }
+    }
 }
 
 #[allow(clippy::must_use_candidate)]
@@ -372,4 +439,76 @@ mod tests {
             Ok(())
         }
     }
+
+    mod satisfied_terms {
+        use crate::id::filter::expression::Result;
+        use crate::id::filter::{Condition, Expression};
+        use crate::id::matcher::Matches;
+        use crate::selector;
+
+        #[test]
+        fn handles_any() -> Result {
+            let expr = Expression::any(|expr| {
+                expr.with(selector!(location = "**/*.png")?)?
+                    .with(selector!(location = "**/*.jpg")?)
+            })?;
+            let condition = Condition::builder(expr).optimize().build();
+            for (matches, check) in [
+                (Matches::from_iter([]), Vec::new()),
+                (Matches::from_iter([0]), Vec::from([0])),
+                (Matches::from_iter([1]), Vec::from([1])),
+                (Matches::from_iter([0, 1]), Vec::from([0, 1])),
+                (Matches::from_iter([0, 1, 2]), Vec::from([0, 1])),
+                (Matches::from_iter([2]), Vec::new()),
+            ] {
+                let terms = condition.satisfied_terms(&matches);
+                assert_eq!(terms.into_iter().collect::<Vec<_>>(), check);
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn handles_not() -> Result {
+            let expr = Expression::not(|expr| {
+                expr.with(selector!(location = "**/*.png")?)?
+                    .with(selector!(location = "**/*.jpg")?)
+            })?;
+            let condition = Condition::builder(expr).optimize().build();
+            for matches in [
+                Matches::from_iter([]),
+                Matches::from_iter([0]),
+                Matches::from_iter([2]),
+            ] {
+                assert!(condition.satisfied_terms(&matches).is_empty());
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn handles_all_any_not() -> Result {
+            let expr = Expression::all(|expr| {
+                expr.with(selector!(provider = "file")?)?
+                    .with(Expression::any(|expr| {
+                        expr.with(selector!(context = "docs")?)? // fmt
+                            .with(Expression::not(|expr| {
+                                expr.with(selector!(location = "**/*.png")?)?
+                                    .with(selector!(location = "**/*.jpg")?)
+                            }),
+                        )
+                    }))
+            })?;
+            let condition = Condition::builder(expr).build();
+            for (matches, check) in [
+                (Matches::from_iter([]), Vec::new()),
+                (Matches::from_iter([0]), Vec::from([0])),
+                (Matches::from_iter([0, 1]), Vec::from([0, 1])),
+                (Matches::from_iter([0, 2]), Vec::new()),
+                (Matches::from_iter([0, 1, 2]), Vec::from([0, 1])),
+            ] {
+                let terms = condition.satisfied_terms(&matches);
+                assert_eq!(terms.into_iter().collect::<Vec<_>>(), check);
+            }
+            Ok(())
+        }
+    }
 }
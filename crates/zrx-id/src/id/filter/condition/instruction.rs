@@ -33,7 +33,7 @@ use crate::id::matcher::Matches;
 // ----------------------------------------------------------------------------
 
 /// Instruction.
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Instruction {
     /// Compare terms against matches.
     Compare(Operator, Matches),
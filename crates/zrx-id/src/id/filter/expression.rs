@@ -25,6 +25,7 @@
 
 //! Expression.
 
+use std::fmt;
 use std::vec::IntoIter;
 
 mod builder;
@@ -137,3 +138,134 @@ impl IntoIterator for Expression {
         self.operands.into_iter()
     }
 }
+
+// ----------------------------------------------------------------------------
+
+impl fmt::Display for Expression {
+    /// Formats the expression for display.
+    ///
+    /// Expressions are rendered in infix notation, joining operands with `OR`
+    /// and `AND`, and prefixing them with `NOT`. Nested expressions with more
+    /// than one operand are wrapped in parentheses, and terms are rendered as
+    /// identifiers or selectors. This is intended for debugging purposes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::{selector, Expression};
+    ///
+    /// // Create expression
+    /// let expr = Expression::all(|expr| {
+    ///     expr.with(selector!(location = "**/*.md")?)?
+    ///         .with(Expression::not(|expr| {
+    ///             expr.with(selector!(provider = "file")?)
+    ///         })
+    ///     )
+    /// })?;
+    ///
+    /// // Format expression
+    /// assert_eq!(
+    ///     expr.to_string(),
+    ///     "zrs:::::**/*.md: AND NOT zrs:file:::::"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let separator = match self.operator {
+            Operator::Any => " OR ",
+            Operator::All => " AND ",
+            Operator::Not => {
+                f.write_str("NOT ")?;
+                if self.operands.len() == 1 {
+                    return write!(f, "{}", self.operands[0]);
+                }
+
+                // Negate the disjunction of all operands
+                f.write_str("(")?;
+                fmt_operands(f, &self.operands, " OR ")?;
+                return f.write_str(")");
+            }
+        };
+        fmt_operands(f, &self.operands, separator)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Formats the given operands, joined by the given separator.
+fn fmt_operands(
+    f: &mut fmt::Formatter<'_>, operands: &[Operand], separator: &str,
+) -> fmt::Result {
+    for (n, operand) in operands.iter().enumerate() {
+        if n > 0 {
+            f.write_str(separator)?;
+        }
+        write!(f, "{operand}")?;
+    }
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod fmt {
+        use crate::id::filter::expression::Result;
+        use crate::id::filter::Expression;
+        use crate::selector;
+
+        #[test]
+        fn handles_nested() -> Result {
+            let expr = Expression::all(|expr| {
+                expr.with(Expression::any(|expr| {
+                    expr.with(selector!(location = "**/*.png")?)?
+                        .with(selector!(location = "**/*.jpg")?)
+                })?)?
+                .with(Expression::not(|expr| {
+                    expr.with(selector!(provider = "file")?)
+                })?)
+            })?;
+            assert_eq!(
+                expr.to_string(),
+                "(zrs:::::**/*.png: OR zrs:::::**/*.jpg:) \
+                 AND NOT zrs:file:::::"
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_not() -> Result {
+            let expr = Expression::not(|expr| {
+                expr.with(selector!(provider = "file")?)?
+                    .with(Expression::all(|expr| {
+                        expr.with(selector!(context = "docs")?)?
+                            .with(selector!(location = "**/*.md")?)
+                    })?)
+            })?;
+            assert_eq!(
+                expr.to_string(),
+                "NOT (zrs:file::::: OR (zrs::::docs:: AND zrs:::::**/*.md:))"
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_single() -> Result {
+            let expr = Expression::any(|expr| {
+                expr.with(Expression::all(|expr| {
+                    expr.with(selector!(location = "**/*.md")?)
+                })?)
+            })?;
+            assert_eq!(expr.to_string(), "zrs:::::**/*.md:");
+            Ok(())
+        }
+    }
+}
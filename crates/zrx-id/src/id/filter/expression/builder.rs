@@ -25,7 +25,7 @@
 
 //! Expression builder.
 
-use super::error::Result;
+use super::error::{Error, Result};
 use super::operand::{Operand, Operator, TryIntoOperand};
 use super::Expression;
 
@@ -51,8 +51,10 @@ impl Expression {
     ///
     /// # Errors
     ///
-    /// This method returns [`Error::Id`][] if any of the operands is invalid.
+    /// This method returns [`Error::Id`][] if any of the operands is invalid,
+    /// and [`Error::Empty`][] if the expression has no operands.
     ///
+    /// [`Error::Empty`]: crate::id::filter::expression::Error::Empty
     /// [`Error::Id`]: crate::id::filter::expression::Error::Id
     ///
     /// # Examples
@@ -79,15 +81,57 @@ impl Expression {
             operator: Operator::Any,
             operands: Vec::new(),
         })
-        .map(Builder::build)
+        .and_then(Builder::build)
+    }
+
+    /// Creates an expression for which any of the given operands must match.
+    ///
+    /// This method is equivalent to calling [`Expression::any`] and adding
+    /// each operand via [`Builder::with`], which is useful when operands are
+    /// obtained dynamically, e.g., from a list of selectors in configuration.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Id`][] if any of the operands is invalid,
+    /// and [`Error::Empty`][] if the expression has no operands.
+    ///
+    /// [`Error::Empty`]: crate::id::filter::expression::Error::Empty
+    /// [`Error::Id`]: crate::id::filter::expression::Error::Id
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::{selector, Expression};
+    ///
+    /// // Create expression
+    /// let expr = Expression::any_of([
+    ///     selector!(location = "**/*.png")?,
+    ///     selector!(location = "**/*.jpg")?,
+    /// ])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn any_of<I, T>(operands: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = T>,
+        T: TryIntoOperand,
+    {
+        Expression::any(|expr| {
+            operands.into_iter().try_fold(expr, Builder::with)
+        })
     }
 
     /// Creates an expression for which all operands must match.
     ///
     /// # Errors
     ///
-    /// This method returns [`Error::Id`][] if any of the operands is invalid.
+    /// This method returns [`Error::Id`][] if any of the operands is invalid,
+    /// and [`Error::Empty`][] if the expression has no operands.
     ///
+    /// [`Error::Empty`]: crate::id::filter::expression::Error::Empty
     /// [`Error::Id`]: crate::id::filter::expression::Error::Id
     ///
     /// # Examples
@@ -114,15 +158,57 @@ impl Expression {
             operator: Operator::All,
             operands: Vec::new(),
         })
-        .map(Builder::build)
+        .and_then(Builder::build)
+    }
+
+    /// Creates an expression for which all of the given operands must match.
+    ///
+    /// This method is equivalent to calling [`Expression::all`] and adding
+    /// each operand via [`Builder::with`], which is useful when operands are
+    /// obtained dynamically, e.g., from a list of selectors in configuration.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Id`][] if any of the operands is invalid,
+    /// and [`Error::Empty`][] if the expression has no operands.
+    ///
+    /// [`Error::Empty`]: crate::id::filter::expression::Error::Empty
+    /// [`Error::Id`]: crate::id::filter::expression::Error::Id
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::{selector, Expression};
+    ///
+    /// // Create expression
+    /// let expr = Expression::all_of([
+    ///     selector!(location = "**/*.png")?,
+    ///     selector!(location = "**/*.jpg")?,
+    /// ])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn all_of<I, T>(operands: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = T>,
+        T: TryIntoOperand,
+    {
+        Expression::all(|expr| {
+            operands.into_iter().try_fold(expr, Builder::with)
+        })
     }
 
     /// Creates an expression for which no operand must match.
     ///
     /// # Errors
     ///
-    /// This method returns [`Error::Id`][] if any of the operands is invalid.
+    /// This method returns [`Error::Id`][] if any of the operands is invalid,
+    /// and [`Error::Empty`][] if the expression has no operands.
     ///
+    /// [`Error::Empty`]: crate::id::filter::expression::Error::Empty
     /// [`Error::Id`]: crate::id::filter::expression::Error::Id
     ///
     /// # Examples
@@ -152,7 +238,7 @@ impl Expression {
             operator: Operator::Not,
             operands: Vec::new(),
         })
-        .map(Builder::build)
+        .and_then(Builder::build)
     }
 }
 
@@ -194,12 +280,124 @@ impl Builder {
     /// Builds the expression.
     ///
     /// This method is private, as building is done implicitly through the
-    /// construction methods defined as part of [`Expression`].
-    #[must_use]
-    fn build(self) -> Expression {
-        Expression {
+    /// construction methods defined as part of [`Expression`]. Expressions
+    /// without operands are rejected, since their semantics are ambiguous.
+    fn build(self) -> Result<Expression> {
+        if self.operands.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        // Return expression with operator and operands
+        Ok(Expression {
             operator: self.operator,
             operands: self.operands,
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod build {
+        use crate::id::filter::expression::{Error, Result};
+        use crate::id::filter::Expression;
+        use crate::selector;
+
+        #[test]
+        fn handles_any() -> Result {
+            let expr = Expression::any(|expr| {
+                expr.with(selector!(location = "**/*.md")?)
+            })?;
+            assert_eq!(expr.operands().len(), 1);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_empty_any() {
+            let res = Expression::any(Ok);
+            assert!(matches!(res, Err(Error::Empty)));
+        }
+
+        #[test]
+        fn handles_empty_all() {
+            let res = Expression::all(Ok);
+            assert!(matches!(res, Err(Error::Empty)));
+        }
+
+        #[test]
+        fn handles_empty_not() {
+            let res = Expression::not(Ok);
+            assert!(matches!(res, Err(Error::Empty)));
+        }
+    }
+
+    mod any_of {
+        use crate::id::filter::expression::{Error, Result};
+        use crate::id::filter::{Condition, Expression};
+        use crate::id::matcher::selector::Selector;
+        use crate::selector;
+
+        #[test]
+        fn handles_selectors() -> Result {
+            let selectors: Vec<Selector> = Vec::from([
+                "zrs:::::**/*.md:".parse()?,
+                "zrs:::::**/*.png:".parse()?,
+                "zrs:file:::::".parse()?,
+            ]);
+            let expr = Expression::any(|expr| {
+                expr.with(selector!(location = "**/*.md")?)?
+                    .with(selector!(location = "**/*.png")?)?
+                    .with(selector!(provider = "file")?)
+            })?;
+            assert_eq!(
+                Condition::builder(Expression::any_of(selectors)?)
+                    .optimize()
+                    .build(),
+                Condition::builder(expr).optimize().build()
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_empty() {
+            let res = Expression::any_of(Vec::<Selector>::new());
+            assert!(matches!(res, Err(Error::Empty)));
+        }
+    }
+
+    mod all_of {
+        use crate::id::filter::expression::{Error, Result};
+        use crate::id::filter::{Condition, Expression};
+        use crate::id::matcher::selector::Selector;
+        use crate::selector;
+
+        #[test]
+        fn handles_selectors() -> Result {
+            let selectors: Vec<Selector> = Vec::from([
+                "zrs:::::**/*.md:".parse()?,
+                "zrs:file:::::".parse()?,
+            ]);
+            let expr = Expression::all(|expr| {
+                expr.with(selector!(location = "**/*.md")?)?
+                    .with(selector!(provider = "file")?)
+            })?;
+            assert_eq!(
+                Condition::builder(Expression::all_of(selectors)?)
+                    .optimize()
+                    .build(),
+                Condition::builder(expr).optimize().build()
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_empty() {
+            let res = Expression::all_of(Vec::<Selector>::new());
+            assert!(matches!(res, Err(Error::Empty)));
         }
     }
 }
@@ -40,6 +40,10 @@ pub enum Error {
     /// Identifier error.
     #[error(transparent)]
     Id(#[from] id::Error),
+
+    /// Expression has no operands.
+    #[error("expression has no operands")]
+    Empty,
 }
 
 // ----------------------------------------------------------------------------
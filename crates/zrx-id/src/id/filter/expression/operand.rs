@@ -75,6 +75,26 @@ where
 
 // ----------------------------------------------------------------------------
 
+impl fmt::Display for Operand {
+    /// Formats the operand for display.
+    ///
+    /// Expressions with more than one operand are wrapped in parentheses,
+    /// unless negated, so operator precedence is explicit when nesting.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Expression(expr) => {
+                if expr.operator() != Operator::Not && expr.operands().len() > 1
+                {
+                    write!(f, "({expr})")
+                } else {
+                    write!(f, "{expr}")
+                }
+            }
+            Operand::Term(term) => term.fmt(f),
+        }
+    }
+}
+
 impl fmt::Debug for Operand {
     /// Formats the operand for debugging.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
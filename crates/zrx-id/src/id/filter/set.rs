@@ -0,0 +1,485 @@
+// Copyright (c) 2025-2026 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// All contributions are certified under the DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Filter set.
+
+use slab::Slab;
+
+use crate::id::matcher::Matches;
+use crate::id::TryIntoId;
+
+use super::error::Result;
+use super::expression::IntoExpression;
+use super::Filter;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Filter set.
+///
+/// Filter sets manage a set of named expressions, which are compiled into a
+/// shared [`Filter`], and evaluate all of them against an identifier at once,
+/// returning the names of all satisfied conditions. Since the terms of all
+/// expressions are extracted into a single matcher, the identifier is only
+/// matched once, independent of the number of expressions.
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_id::filter::FilterSet;
+/// use zrx_id::{selector, Expression, Id};
+///
+/// // Create filter set and insert named expressions
+/// let mut set = FilterSet::new();
+/// set.insert("images", Expression::any(|expr| {
+///     expr.with(selector!(location = "**/*.png")?)?
+///         .with(selector!(location = "**/*.jpg")?)
+/// })?)?;
+///
+/// // Obtain names of satisfied conditions
+/// let id: Id = "zri:file:::docs:image.jpg:".parse()?;
+/// assert_eq!(set.evaluate(&id)?, ["images"]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct FilterSet {
+    /// Names of expressions, indexed like the conditions of the filter.
+    names: Slab<String>,
+    /// Filter, built from expressions.
+    filter: Filter,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl FilterSet {
+    /// Creates a filter set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_id::filter::FilterSet;
+    ///
+    /// // Create filter set
+    /// let set = FilterSet::new();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a named expression into the filter set.
+    ///
+    /// The expression is immediately compiled into an optimized condition, and
+    /// the filter is rebuilt. If an expression with the same name already
+    /// exists, it is replaced, retaining its position in the order of
+    /// evaluation. If the filter can't be rebuilt, the filter set is left
+    /// unchanged.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Matcher`][] if the underlying matcher
+    /// cannot be successfully built.
+    ///
+    /// [`Error::Matcher`]: crate::id::filter::Error::Matcher
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::filter::FilterSet;
+    /// use zrx_id::selector;
+    ///
+    /// // Create filter set and insert named expression
+    /// let mut set = FilterSet::new();
+    /// set.insert("markdown", selector!(location = "**/*.md")?)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn insert<N, T>(&mut self, name: N, expr: T) -> Result
+    where
+        N: Into<String>,
+        T: IntoExpression,
+    {
+        let name = name.into();
+        let mut builder = self.filter.to_builder();
+
+        // Remove the existing expression with the same name, if any, which is
+        // then replaced by the new expression, since slabs always reuse the
+        // most recently vacated slot, so its position is retained
+        let prior = self.position(&name);
+        if let Some(index) = prior {
+            builder.remove(index);
+        }
+
+        // Build filter, and only commit changes if building succeeded
+        let index = builder.insert(expr);
+        self.filter = builder.build()?;
+        if prior.is_some() {
+            self.names[index] = name;
+        } else {
+            let check = self.names.insert(name);
+            debug_assert_eq!(check, index);
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+
+    /// Removes a named expression from the filter set.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Matcher`][] if the underlying matcher
+    /// cannot be successfully built.
+    ///
+    /// [`Error::Matcher`]: crate::id::filter::Error::Matcher
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::filter::FilterSet;
+    /// use zrx_id::selector;
+    ///
+    /// // Create filter set and insert named expression
+    /// let mut set = FilterSet::new();
+    /// set.insert("markdown", selector!(location = "**/*.md")?)?;
+    ///
+    /// // Remove named expression
+    /// set.remove("markdown")?;
+    /// assert!(set.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn remove(&mut self, name: &str) -> Result {
+        if let Some(index) = self.position(name) {
+            let mut builder = self.filter.to_builder();
+            builder.remove(index);
+
+            // Build filter, and only commit changes if building succeeded
+            self.filter = builder.build()?;
+            self.names.remove(index);
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+
+    /// Returns the names of all conditions satisfied by the identifier.
+    ///
+    /// Names are returned in the order of evaluation, which is the order in
+    /// which the expressions were inserted, except for expressions inserted
+    /// after removals, which take over the positions of removed expressions.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Matcher`][] if the identifier is invalid.
+    ///
+    /// [`Error::Matcher`]: crate::id::filter::Error::Matcher
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::filter::FilterSet;
+    /// use zrx_id::{selector, Id};
+    ///
+    /// // Create filter set and insert named expression
+    /// let mut set = FilterSet::new();
+    /// set.insert("markdown", selector!(location = "**/*.md")?)?;
+    ///
+    /// // Obtain names of satisfied conditions
+    /// let id: Id = "zri:file:::docs:index.md:".parse()?;
+    /// assert_eq!(set.evaluate(&id)?, ["markdown"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn evaluate<T>(&self, id: &T) -> Result<Vec<&str>>
+    where
+        T: TryIntoId,
+    {
+        let iter = self.filter.candidates(id)?;
+        Ok(iter.map(|index| self.names[index].as_str()).collect())
+    }
+
+    /// Returns the terms that contributed to satisfying the named condition.
+    ///
+    /// This is useful for highlighting which terms were responsible for the
+    /// condition being satisfied by the identifier. Terms are identified by
+    /// their index in the named expression. If no condition with the given
+    /// name exists, [`None`] is returned.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Matcher`][] if the identifier is invalid.
+    ///
+    /// [`Error::Matcher`]: crate::id::filter::Error::Matcher
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::filter::FilterSet;
+    /// use zrx_id::{selector, Expression, Id, Matches};
+    ///
+    /// // Create filter set and insert named expression
+    /// let mut set = FilterSet::new();
+    /// set.insert("images", Expression::any(|expr| {
+    ///     expr.with(selector!(location = "**/*.png")?)?
+    ///         .with(selector!(location = "**/*.jpg")?)
+    /// })?)?;
+    ///
+    /// // Obtain terms that satisfied the condition
+    /// let id: Id = "zri:file:::docs:image.jpg:".parse()?;
+    /// let terms = set.satisfied_terms("images", &id)?;
+    /// assert_eq!(terms, Some(Matches::from_iter([1])));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn satisfied_terms<T>(
+        &self, name: &str, id: &T,
+    ) -> Result<Option<Matches>>
+    where
+        T: TryIntoId,
+    {
+        let Some(index) = self.position(name) else {
+            return Ok(None);
+        };
+
+        // The terms of all conditions are added to the matcher in order, so
+        // we determine the offset of the first term of the condition, and
+        // translate the matches of its terms into term indices of the condition
+        let mapping = &self.filter.mapping;
+        let start = mapping
+            .iter()
+            .position(|&check| check as usize == index)
+            .unwrap_or(mapping.len());
+
+        // Collect all matches that belong to the condition
+        let mut matches = Matches::default();
+        for term in self.filter.matcher.matches(id)? {
+            if mapping[term] as usize == index {
+                matches.insert(term - start);
+            }
+        }
+
+        // Return terms that satisfied the condition
+        let condition = &self.filter.conditions[index];
+        Ok(Some(condition.satisfied_terms(&matches)))
+    }
+
+    /// Returns the index of the expression with the given name.
+    fn position(&self, name: &str) -> Option<usize> {
+        let mut iter = self.names.iter();
+        iter.find(|(_, check)| *check == name)
+            .map(|(index, _)| index)
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl FilterSet {
+    /// Returns the number of expressions.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns whether there are any expressions.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod evaluate {
+        use crate::id::filter::{Expression, FilterSet, Result};
+        use crate::selector;
+
+        #[test]
+        fn handles_conditions() -> Result {
+            let mut set = FilterSet::new();
+            set.insert(
+                "images",
+                Expression::any(|expr| {
+                    expr.with(selector!(location = "**/*.png")?)?
+                        .with(selector!(location = "**/*.jpg")?)
+                })?,
+            )?;
+            set.insert(
+                "markdown",
+                Expression::all(|expr| {
+                    expr.with(selector!(location = "**/*.md")?)?
+                        .with(selector!(provider = "file")?)
+                })?,
+            )?;
+            for (id, check) in [
+                ("zri:file:::docs:image.png:", vec!["images"]),
+                ("zri:file:::docs:image.jpg:", vec!["images"]),
+                ("zri:file:::docs:index.md:", vec!["markdown"]),
+                ("zri:git:::docs:index.md:", vec![]),
+                ("zri:file:::docs:image.gif:", vec![]),
+            ] {
+                assert_eq!(set.evaluate(&id)?, check);
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn handles_overlapping() -> Result {
+            let mut set = FilterSet::new();
+            set.insert(
+                "markdown",
+                Expression::any(|expr| {
+                    expr.with(selector!(location = "**/*.md")?)
+                })?,
+            )?;
+            set.insert(
+                "docs",
+                Expression::any(|expr| {
+                    expr.with(selector!(context = "docs")?)
+                })?,
+            )?;
+            for (id, check) in [
+                ("zri:file:::docs:index.md:", vec!["markdown", "docs"]),
+                ("zri:file:::blog:index.md:", vec!["markdown"]),
+                ("zri:file:::docs:image.png:", vec!["docs"]),
+            ] {
+                assert_eq!(set.evaluate(&id)?, check);
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn handles_replace() -> Result {
+            let mut set = FilterSet::new();
+            set.insert(
+                "a",
+                Expression::any(|expr| {
+                    expr.with(selector!(location = "**/*.md")?)
+                })?,
+            )?;
+            set.insert(
+                "b",
+                Expression::any(|expr| {
+                    expr.with(selector!(location = "**/*.md")?)
+                })?,
+            )?;
+            set.insert(
+                "a",
+                Expression::any(|expr| {
+                    expr.with(selector!(location = "**/*.png")?)
+                })?,
+            )?;
+            let id = "zri:file:::docs:index.md:";
+            assert_eq!(set.evaluate(&id)?, ["b"]);
+            let id = "zri:file:::docs:image.png:";
+            assert_eq!(set.evaluate(&id)?, ["a"]);
+            assert_eq!(set.len(), 2);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_remove() -> Result {
+            let mut set = FilterSet::new();
+            set.insert(
+                "a",
+                Expression::any(|expr| {
+                    expr.with(selector!(location = "**/*.md")?)
+                })?,
+            )?;
+            set.insert(
+                "b",
+                Expression::any(|expr| {
+                    expr.with(selector!(location = "**/*.md")?)
+                })?,
+            )?;
+            set.remove("a")?;
+            let id = "zri:file:::docs:index.md:";
+            assert_eq!(set.evaluate(&id)?, ["b"]);
+            assert_eq!(set.len(), 1);
+            Ok(())
+        }
+    }
+
+    mod satisfied_terms {
+        use crate::id::filter::{Expression, FilterSet, Result};
+        use crate::id::matcher::Matches;
+        use crate::selector;
+
+        #[test]
+        fn handles_conditions() -> Result {
+            let mut set = FilterSet::new();
+            set.insert(
+                "markdown",
+                Expression::all(|expr| {
+                    expr.with(selector!(location = "**/*.md")?)?
+                        .with(selector!(provider = "file")?)
+                })?,
+            )?;
+            set.insert(
+                "images",
+                Expression::any(|expr| {
+                    expr.with(selector!(location = "**/*.png")?)?
+                        .with(selector!(location = "**/*.jpg")?)
+                })?,
+            )?;
+            for (name, id, check) in [
+                ("images", "zri:file:::docs:image.png:", vec![0]),
+                ("images", "zri:file:::docs:image.jpg:", vec![1]),
+                ("images", "zri:file:::docs:index.md:", vec![]),
+                ("markdown", "zri:file:::docs:index.md:", vec![0, 1]),
+                ("markdown", "zri:git:::docs:index.md:", vec![]),
+            ] {
+                assert_eq!(
+                    set.satisfied_terms(name, &id)?,
+                    Some(Matches::from_iter(check))
+                );
+            }
+            assert_eq!(
+                set.satisfied_terms("unknown", &"zri:file:::docs:index.md:")?,
+                None
+            );
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright (c) 2025-2026 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// All contributions are certified under the DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Identifier component mask.
+
+use std::ops::{BitOr, BitOrAssign};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Identifier component mask.
+///
+/// Masks select a subset of the components of an [`Id`][], e.g., to copy them
+/// from another identifier with [`Builder::inherit_from`][]. Masks for single
+/// components are provided as constants, and can be combined with `|`.
+///
+/// [`Builder::inherit_from`]: crate::id::Builder::inherit_from
+/// [`Id`]: crate::id::Id
+///
+/// # Examples
+///
+/// ```
+/// use zrx_id::ComponentMask;
+///
+/// // Create mask for provider and location
+/// let mask = ComponentMask::PROVIDER | ComponentMask::LOCATION;
+/// assert!(mask.contains(ComponentMask::PROVIDER));
+/// assert!(!mask.contains(ComponentMask::CONTEXT));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ComponentMask(u8);
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl ComponentMask {
+    /// Mask selecting no components.
+    pub const NONE: Self = Self(0);
+    /// Mask selecting the `provider` component.
+    pub const PROVIDER: Self = Self(1 << 1);
+    /// Mask selecting the `resource` component.
+    pub const RESOURCE: Self = Self(1 << 2);
+    /// Mask selecting the `variant` component.
+    pub const VARIANT: Self = Self(1 << 3);
+    /// Mask selecting the `context` component.
+    pub const CONTEXT: Self = Self(1 << 4);
+    /// Mask selecting the `location` component.
+    pub const LOCATION: Self = Self(1 << 5);
+    /// Mask selecting the `fragment` component.
+    pub const FRAGMENT: Self = Self(1 << 6);
+    /// Mask selecting all components.
+    pub const ALL: Self = Self(0b0111_1110);
+
+    /// Returns whether the mask contains all components of the given mask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_id::ComponentMask;
+    ///
+    /// // Ensure mask contains component
+    /// let mask = ComponentMask::ALL;
+    /// assert!(mask.contains(ComponentMask::CONTEXT));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns whether the mask contains the component at the given index,
+    /// which is the index of the component in the underlying format.
+    #[inline]
+    pub(crate) fn has_index(self, index: usize) -> bool {
+        self.0 & (1 << index) != 0
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl BitOr for ComponentMask {
+    type Output = Self;
+
+    /// Combines both masks.
+    #[inline]
+    fn bitor(self, other: Self) -> Self::Output {
+        Self(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for ComponentMask {
+    /// Combines both masks in place.
+    #[inline]
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+}
@@ -25,9 +25,14 @@
 
 //! Matcher.
 
+#[cfg(feature = "serde")]
+use std::borrow::Cow;
+#[cfg(feature = "serde")]
+use std::result;
 use std::str::FromStr;
 
 use super::convert::TryIntoId;
+use super::Id;
 
 mod builder;
 mod component;
@@ -35,10 +40,12 @@ mod error;
 pub mod matches;
 pub mod selector;
 
+use builder::compile;
 pub use builder::Builder;
 use component::Component;
 pub use error::{Error, Result};
 pub use matches::Matches;
+use selector::Selector;
 
 // ----------------------------------------------------------------------------
 // Structs
@@ -95,6 +102,10 @@ pub struct Matcher {
     location: Component,
     /// Component for selector.
     fragment: Component,
+    /// Selectors in order of addition.
+    selectors: Vec<String>,
+    /// Matcher for exclusions, if any.
+    exclusions: Option<Box<Matcher>>,
 }
 
 // ----------------------------------------------------------------------------
@@ -104,6 +115,9 @@ pub struct Matcher {
 impl Matcher {
     /// Returns whether the given identifier matches any selector.
     ///
+    /// If exclusions were added to the matcher, the identifier must not match
+    /// any of them, as a matching exclusion suppresses all other matches.
+    ///
     /// Components are compared in descending variability and their likelihood
     /// for mismatch, starting with the `location`. This approach effectively
     /// tries to short-circuits the comparison. Note that empty components are
@@ -146,7 +160,8 @@ impl Matcher {
     /// This method compares each component of the identifier against the
     /// corresponding component of a selector using the compiled globs, and
     /// returns the indices of the matching selectors in the order they were
-    /// added to the [`Matcher`].
+    /// added to the [`Matcher`]. If the identifier matches any exclusion, no
+    /// indices are returned, regardless of the matching selectors.
     ///
     /// Components are compared in descending variability and their likelihood
     /// for mismatch, starting with the `location`. This approach effectively
@@ -177,13 +192,161 @@ impl Matcher {
     /// # Ok(())
     /// # }
     /// ```
-    #[allow(clippy::missing_panics_doc)]
+    #[inline]
     pub fn matches<T>(&self, id: &T) -> Result<Matches>
     where
         T: TryIntoId,
     {
         let id = id.try_into_id()?;
+        Ok(self.matches_into(&id, &mut Vec::new()))
+    }
+
+    /// Returns the index of the first selector that matches the identifier.
+    ///
+    /// When selectors are added in order of priority, this method returns the
+    /// selector with the highest priority, which is the lowest index, and thus
+    /// the first index returned by [`Matcher::matches`]. All selectors are
+    /// matched at once through the glob sets of the components, which is much
+    /// faster than checking selectors one by one, and matching stops as soon
+    /// as no selector matches all components compared so far.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Id`] if the identifier is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Matcher;
+    ///
+    /// // Create matcher builder and add selectors
+    /// let mut builder = Matcher::builder();
+    /// builder.add(&"zrs:::::**/*.png:")?;
+    /// builder.add(&"zrs:::::**/*.md:")?;
+    /// builder.add(&"zrs::::::")?;
+    ///
+    /// // Create matcher from builder
+    /// let matcher = builder.build()?;
+    ///
+    /// // Obtain first matched selector
+    /// let index = matcher.matches_first(&"zri:file:::docs:index.md:")?;
+    /// assert_eq!(index, Some(1));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn matches_first<T>(&self, id: &T) -> Result<Option<usize>>
+    where
+        T: TryIntoId,
+    {
+        self.matches(id).map(|matches| matches.iter().next())
+    }
+
+    /// Returns the indices of selectors that match each of the identifiers.
+    ///
+    /// This method is equivalent to calling [`Matcher::matches`] for each of
+    /// the given identifiers, but reuses a single buffer for the glob sets of
+    /// all components, amortizing allocations across the entire batch.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Id`] if any identifier is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::{Matcher, Matches};
+    ///
+    /// // Create matcher builder and add selector
+    /// let mut builder = Matcher::builder();
+    /// builder.add(&"zrs:::::**/*.md:")?;
+    ///
+    /// // Create matcher from builder
+    /// let matcher = builder.build()?;
+    ///
+    /// // Obtain matched selectors for identifiers
+    /// let matches = matcher.matches_many([
+    ///     "zri:file:::docs:index.md:",
+    ///     "zri:file:::docs:image.png:",
+    /// ])?;
+    /// assert_eq!(matches, [Matches::from_iter([0]), Matches::default()]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn matches_many<I, T>(&self, ids: I) -> Result<Vec<Matches>>
+    where
+        I: IntoIterator<Item = T>,
+        T: TryIntoId,
+    {
+        let mut slots = Vec::new();
+        ids.into_iter()
+            .map(|id| {
+                let id = id.try_into_id()?;
+                Ok(self.matches_into(&id, &mut slots))
+            })
+            .collect()
+    }
 
+    /// Merges the matcher with the given matcher.
+    ///
+    /// This method creates a matcher from the selectors of both matchers, so
+    /// the indices of the first matcher's selectors are retained, and those of
+    /// the second matcher's selectors are offset by the number of selectors of
+    /// the first matcher. Since glob sets can't be combined, they're rebuilt.
+    ///
+    /// Note that exclusions of both matchers are merged as well, and thus apply
+    /// to the selectors of both matchers in the resulting matcher.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the [`GlobSet`][] that is associated
+    /// with a component cannot be successfully built.
+    ///
+    /// [`GlobSet`]: globset::GlobSet
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::{Matcher, Matches};
+    ///
+    /// // Create matchers from strings
+    /// let a: Matcher = "zrs:::::**/*.md:".parse()?;
+    /// let b: Matcher = "zrs:::::**/*.png:".parse()?;
+    ///
+    /// // Merge matchers and obtain matched selectors
+    /// let matcher = a.merge(b)?;
+    /// let matches = matcher.matches(&"zri:file:::docs:image.png:")?;
+    /// assert_eq!(matches, Matches::from_iter([1]));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn merge(self, other: Matcher) -> Result<Self> {
+        let mut builder = Matcher::builder();
+        for selector in self.selectors.iter().chain(&other.selectors) {
+            builder.add(selector)?;
+        }
+
+        // Add exclusions of both matchers, if any
+        let iter = [self.exclusions, other.exclusions].into_iter().flatten();
+        for exclusions in iter {
+            for selector in exclusions.selectors() {
+                builder.add_exclusion(selector)?;
+            }
+        }
+
+        // Build merged matcher
+        builder.build()
+    }
+
+    /// Returns the indices of selectors that match the identifier, using the
+    /// given slots as a buffer for the glob sets of all components.
+    fn matches_into(&self, id: &Id, slots: &mut Vec<usize>) -> Matches {
         // Query all components from highest to lowest variability, and
         // intersect the resulting match sets, keeping only full matches
         let mut opt: Option<Matches> = None;
@@ -200,7 +363,7 @@ impl Matcher {
             // but we would then miss selectors that use explicit `*` or `**`
             // wildcards. We use the unlikely `U+FFFE` to test for those.
             let path = value.as_deref().unwrap_or("\u{FFFE}");
-            let matches = component.matches(path);
+            let matches = component.matches(path, slots);
 
             // Intersect with or set as tracking match set
             if let Some(tracked) = &mut opt {
@@ -208,10 +371,124 @@ impl Matcher {
             } else {
                 opt = Some(matches);
             }
+
+            // If no selector matches all components compared so far, none of
+            // the remaining components can add matches, so we stop early
+            if opt.as_ref().is_some_and(Matches::is_empty) {
+                return Matches::default();
+            }
+        }
+
+        // If the identifier matches any exclusion, all matches are discarded,
+        // which we only need to check for when there are any matches at all
+        let matches = opt.expect("invariant");
+        if let Some(exclusions) = &self.exclusions {
+            if !matches.is_empty()
+                && !exclusions.matches_into(id, slots).is_empty()
+            {
+                return Matches::default();
+            }
         }
 
         // Return matches
-        Ok(opt.expect("invariant"))
+        matches
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl Matcher {
+    /// Returns the selectors the matcher was built from.
+    ///
+    /// Selectors are returned in the order in which they were added, so that
+    /// their positions correspond to the indices in [`Matches`]. Since glob
+    /// sets can't be serialized, this allows to reconstruct the matcher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Matcher;
+    ///
+    /// // Create matcher from string
+    /// let matcher: Matcher = "zrs:::::**/*.md:".parse()?;
+    /// assert_eq!(matcher.selectors(), ["zrs:::::**/*.md:"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn selectors(&self) -> &[String] {
+        &self.selectors
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Matcher {
+    /// Returns the selectors and exclusions for serialization.
+    ///
+    /// Exclusions are prefixed with `!`, and follow after all selectors, which
+    /// is unambiguous, since selectors must start with the `zrs:` prefix.
+    fn entries(&self) -> impl Iterator<Item = Cow<'_, str>> {
+        let exclusions = self.exclusions.iter().flat_map(|exclusions| {
+            let iter = exclusions.selectors.iter();
+            iter.map(|selector| Cow::Owned(format!("!{selector}")))
+        });
+        let iter = self.selectors.iter().map(Cow::from);
+        iter.chain(exclusions)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl Id {
+    /// Returns whether the identifier matches the given selector.
+    ///
+    /// This method is a convenience for one-off checks, e.g., in scripts and
+    /// tests, as it compiles and matches each component of the selector one
+    /// after another, without building a [`Matcher`]. Nothing is cached, so
+    /// a [`Matcher`] should be used when matching many identifiers.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Id`] if the selector is invalid, and
+    /// [`Error::Glob`] if a component can't be compiled into a valid glob.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Id;
+    ///
+    /// // Create identifier and match selector
+    /// let id: Id = "zri:file:::docs:index.md:".parse()?;
+    /// assert!(id.matches_selector("zrs:::::**/*.md:")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn matches_selector(&self, selector: &str) -> Result<bool> {
+        let selector: Selector = selector.parse()?;
+
+        // Match components in the same order as the matcher, and use the same
+        // placeholder for missing values, short-circuiting on the first miss
+        for (pattern, value) in [
+            (selector.location(), Some(self.location())),
+            (selector.context(), Some(self.context())),
+            (selector.provider(), Some(self.provider())),
+            (selector.resource(), self.resource()),
+            (selector.fragment(), self.fragment()),
+            (selector.variant(), self.variant()),
+        ] {
+            if let Some(glob) = compile(pattern.as_deref())? {
+                let path = value.as_deref().unwrap_or("\u{FFFE}");
+                if !glob.compile_matcher().is_match(path) {
+                    return Ok(false);
+                }
+            }
+        }
+
+        // All components match
+        Ok(true)
     }
 }
 
@@ -255,6 +532,49 @@ impl FromStr for Matcher {
     }
 }
 
+// ----------------------------------------------------------------------------
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Matcher {
+    /// Serializes the matcher as a sequence of selectors.
+    ///
+    /// Exclusions are appended to the sequence, each prefixed with `!`, so
+    /// the matcher can be reconstructed with the exact same behavior.
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.entries())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Matcher {
+    /// Deserializes the matcher from a sequence of selectors.
+    ///
+    /// All selectors are added to a new matcher builder in order, which is
+    /// then built, recompiling the glob sets of all components. Selectors
+    /// prefixed with `!` are added as exclusions.
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error as _;
+
+        // Deserialize selectors, and build matcher from them
+        let selectors = Vec::<String>::deserialize(deserializer)?;
+        let mut builder = Matcher::builder();
+        for selector in &selectors {
+            match selector.strip_prefix('!') {
+                Some(selector) => builder.add_exclusion(&selector),
+                None => builder.add(selector),
+            }
+            .map_err(D::Error::custom)?;
+        }
+        builder.build().map_err(D::Error::custom)
+    }
+}
+
 // ----------------------------------------------------------------------------
 // Tests
 // ----------------------------------------------------------------------------
@@ -322,6 +642,47 @@ mod tests {
         }
     }
 
+    mod matches_selector {
+        use crate::id::matcher::{Matcher, Result};
+        use crate::id::Id;
+
+        #[test]
+        fn handles_extensions() -> Result {
+            let md: Id = "zri:file:::docs:index.md:".parse()?;
+            let png: Id = "zri:file:::docs:image.png:".parse()?;
+            assert!(md.matches_selector("zrs:::::**/*.md:")?);
+            assert!(!png.matches_selector("zrs:::::**/*.md:")?);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_fixtures() -> Result {
+            let id: Id = "zri:file:::docs:index.md:".parse()?;
+            for selector in &[
+                "zrs:file:::docs:index.md:",
+                "zrs:*:*:*:*:*:",
+                "zrs:{git,file}:::{docs}:index.md:",
+                "zrs:::::{*}:",
+                "zrs:file:::{docs}:index.md:anchor",
+                "zrs:{git,file}:master::::",
+                "zrs::::::anchor",
+            ] {
+                let matcher: Matcher = selector.parse()?;
+                assert_eq!(
+                    id.matches_selector(selector)?,
+                    matcher.is_match(&id)?
+                );
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn handles_invalid() {
+            let id: Id = "zri:file:::docs:index.md:".parse().unwrap();
+            assert!(id.matches_selector("zri:::::**/*.md:").is_err());
+        }
+    }
+
     mod matches {
         use crate::id::matcher::{Matcher, Matches, Result};
 
@@ -393,4 +754,267 @@ mod tests {
             Ok(())
         }
     }
+
+    mod exclusions {
+        use crate::id::matcher::{Matcher, Matches, Result};
+
+        #[test]
+        fn handles_exclusions() -> Result {
+            let mut builder = Matcher::builder();
+            builder.add(&"zrs:::::**/*.md:")?;
+            builder.add_exclusion(&"zrs:::::**/draft/**:")?;
+            let matcher = builder.build()?;
+            assert!(matcher.is_match(&"zri:file:::docs:docs/x.md:")?);
+            assert!(!matcher.is_match(&"zri:file:::docs:draft/x.md:")?);
+            assert!(!matcher.is_match(&"zri:file:::docs:draft/x.png:")?);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_matches() -> Result {
+            let mut builder = Matcher::builder();
+            builder.add(&"zrs:::::**/*.md:")?;
+            builder.add(&"zrs::::docs::")?;
+            builder.add_exclusion(&"zrs:::::**/draft/**:")?;
+            let matcher = builder.build()?;
+            assert_eq!(
+                matcher.matches(&"zri:file:::docs:docs/x.md:")?,
+                Matches::from_iter([0, 1])
+            );
+            assert_eq!(
+                matcher.matches(&"zri:file:::docs:draft/x.md:")?,
+                Matches::default()
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_no_exclusions() -> Result {
+            let matcher: Matcher = "zrs:::::**/*.md:".parse()?;
+            assert!(matcher.is_match(&"zri:file:::docs:draft/x.md:")?);
+            Ok(())
+        }
+    }
+
+    mod max_expansions {
+        use crate::id::matcher::{Error, Matcher, Result};
+
+        #[test]
+        fn handles_exceeded() -> Result {
+            let mut builder = Matcher::builder();
+            builder.add(&"zrs:::::{a,b}{c,d}{e,f}/*.md:")?;
+            builder.max_expansions(4);
+            assert!(matches!(
+                builder.build(),
+                Err(Error::Expansion { count: 8, limit: 4, .. })
+            ));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_within() -> Result {
+            let mut builder = Matcher::builder();
+            builder.add(&"zrs:::::{a,b}{c,{d,e}}/*.md:")?;
+            builder.add(&"zrs:::{x,y}::[{}]*.md:")?;
+            builder.max_expansions(6);
+            let matcher = builder.build()?;
+            assert!(matcher.is_match(&"zri:file:::docs:ae/x.md:")?);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_exclusions() -> Result {
+            let mut builder = Matcher::builder();
+            builder.add(&"zrs:::::**/*.md:")?;
+            builder.add_exclusion(&"zrs:::::{a,b}{c,d}/**:")?;
+            builder.max_expansions(2);
+            assert!(builder.build().is_err());
+            Ok(())
+        }
+    }
+
+    mod matches_first {
+        use crate::id::matcher::{Matcher, Result};
+
+        #[test]
+        fn handles_overlapping() -> Result {
+            let mut builder = Matcher::builder();
+            builder.add(&"zrs:::::**/*.png:")?;
+            builder.add(&"zrs::::docs:**/*.md:")?;
+            builder.add(&"zrs:::::index.md:")?;
+            builder.add(&"zrs::::::")?;
+            let matcher = builder.build()?;
+            for id in [
+                "zri:file:::docs:index.md:",
+                "zri:file:::blog:index.md:",
+                "zri:file:::blog:image.png:",
+            ] {
+                let first = matcher.matches(&id)?.iter().next();
+                assert_eq!(matcher.matches_first(&id)?, first);
+            }
+            assert_eq!(
+                matcher.matches_first(&"zri:file:::docs:a.md:")?,
+                Some(1)
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_non_matches() -> Result {
+            let matcher: Matcher = "zrs:::::**/*.md:".parse()?;
+            assert_eq!(
+                matcher.matches_first(&"zri:file:::docs:image.png:")?,
+                None
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_exclusions() -> Result {
+            let mut builder = Matcher::builder();
+            builder.add(&"zrs:::::**/*.md:")?;
+            builder.add_exclusion(&"zrs:::::**/draft/**:")?;
+            let matcher = builder.build()?;
+            assert_eq!(
+                matcher.matches_first(&"zri:file:::docs:index.md:")?,
+                Some(0)
+            );
+            assert_eq!(
+                matcher.matches_first(&"zri:file:::docs:draft/index.md:")?,
+                None
+            );
+            Ok(())
+        }
+    }
+
+    mod matches_many {
+        use crate::id::matcher::{Matcher, Result};
+
+        #[test]
+        fn handles_selectors() -> Result {
+            let mut builder = Matcher::builder();
+            for selector in &[
+                "zrs:file:::docs:index.md:",
+                "zrs::::docs:*.md:",
+                "zrs:::::**/*.png:",
+                "zrs:git:::::",
+                "zrs::::::anchor",
+            ] {
+                builder.add(selector)?;
+            }
+            let matcher = builder.build()?;
+            let ids = [
+                "zri:file:::docs:index.md:",
+                "zri:file:::docs:about.md:",
+                "zri:git:master::docs:assets/image.png:",
+                "zri:file:::docs:index.md:anchor",
+            ];
+            let mut expected = Vec::new();
+            for id in &ids {
+                expected.push(matcher.matches(id)?);
+            }
+            assert_eq!(matcher.matches_many(ids)?, expected);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_invalid() -> Result {
+            let matcher: Matcher = "zrs::::::".parse()?;
+            assert!(matcher
+                .matches_many(["zri:file:::docs:index.md:", "zri"])
+                .is_err());
+            Ok(())
+        }
+    }
+
+    mod merge {
+        use crate::id::matcher::{Matcher, Matches, Result};
+
+        #[test]
+        fn handles_offset() -> Result {
+            let a: Matcher = "zrs:::::**/*.md:".parse()?;
+            let b: Matcher = "zrs:::::**/*.png:".parse()?;
+            let matcher = a.merge(b)?;
+            assert_eq!(
+                matcher.selectors(),
+                ["zrs:::::**/*.md:", "zrs:::::**/*.png:"]
+            );
+            assert_eq!(
+                matcher.matches(&"zri:file:::docs:image.png:")?,
+                Matches::from_iter([1])
+            );
+            assert_eq!(
+                matcher.matches(&"zri:file:::docs:index.md:")?,
+                Matches::from_iter([0])
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_exclusions() -> Result {
+            let a: Matcher = "zrs:::::**/*.md:".parse()?;
+            let mut builder = Matcher::builder();
+            builder.add(&"zrs:::::**/*.png:")?;
+            builder.add_exclusion(&"zrs:::::**/draft/**:")?;
+            let matcher = a.merge(builder.build()?)?;
+            assert!(matcher.is_match(&"zri:file:::docs:index.md:")?);
+            assert!(!matcher.is_match(&"zri:file:::docs:draft/index.md:")?);
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde {
+        use serde::de::value::{Error, SeqDeserializer};
+        use serde::de::IntoDeserializer;
+        use serde::Deserialize;
+        use std::borrow::Cow;
+
+        use crate::id::matcher::{Matcher, Result};
+
+        #[test]
+        fn handles_round_trip() -> Result {
+            let matcher = Matcher::builder()
+                .with(&"zrs:::::**/*.md:")?
+                .with(&"zrs:file::::**/*.png:")?
+                .with(&"zrs::::docs::")?
+                .build()?;
+            let selectors = matcher.selectors().to_vec();
+            let deserializer: SeqDeserializer<_, Error> =
+                selectors.into_deserializer();
+            let restored = Matcher::deserialize(deserializer).unwrap();
+            assert_eq!(restored.selectors(), matcher.selectors());
+            for id in [
+                "zri:file:::docs:index.md:",
+                "zri:file:::docs:image.png:",
+                "zri:git:::blog:image.png:",
+            ] {
+                assert_eq!(restored.matches(&id)?, matcher.matches(&id)?);
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn handles_round_trip_with_exclusions() -> Result {
+            let mut builder = Matcher::builder();
+            builder.add(&"zrs:::::**/*.md:")?;
+            builder.add_exclusion(&"zrs:::::**/draft/**:")?;
+            let matcher = builder.build()?;
+            let entries =
+                matcher.entries().map(Cow::into_owned).collect::<Vec<_>>();
+            assert_eq!(entries, ["zrs:::::**/*.md:", "!zrs:::::**/draft/**:"]);
+            let deserializer: SeqDeserializer<_, Error> =
+                entries.into_deserializer();
+            let restored = Matcher::deserialize(deserializer).unwrap();
+            assert_eq!(restored.selectors(), matcher.selectors());
+            for id in [
+                "zri:file:::docs:index.md:",
+                "zri:file:::docs:draft/index.md:",
+            ] {
+                assert_eq!(restored.matches(&id)?, matcher.matches(&id)?);
+            }
+            assert!(!restored.is_match(&"zri:file:::docs:draft/index.md:")?);
+            Ok(())
+        }
+    }
 }
@@ -26,9 +26,11 @@
 //! Matcher builder.
 
 use globset::{Glob, GlobBuilder};
+use std::iter::Peekable;
+use std::str::Chars;
 
 use super::component;
-use super::error::Result;
+use super::error::{Error, Result};
 use super::selector::TryIntoSelector;
 use super::Matcher;
 
@@ -51,6 +53,12 @@ pub struct Builder {
     location: component::Builder,
     /// Component builder for fragment.
     fragment: component::Builder,
+    /// Selectors in order of addition.
+    selectors: Vec<String>,
+    /// Builder for exclusions, if any.
+    exclusions: Option<Box<Builder>>,
+    /// Maximum number of brace expansions, if any.
+    max_expansions: Option<usize>,
 }
 
 // ----------------------------------------------------------------------------
@@ -151,16 +159,95 @@ impl Builder {
         self.location.add(compile(selector.location().as_deref())?);
         self.fragment.add(compile(selector.fragment().as_deref())?);
 
+        // Retain selector, so the matcher can be reconstructed from it
+        self.selectors.push(selector.as_str().to_string());
+
+        // Return builder for chaining
+        Ok(self)
+    }
+
+    /// Adds an exclusion to the matcher.
+    ///
+    /// Exclusions are selectors whose match suppresses all other matches, so
+    /// an identifier is only considered a match, if it matches at least one
+    /// selector added via [`Builder::add`], and none of the exclusions. If no
+    /// exclusions are added, the behavior of the matcher is unchanged.
+    ///
+    /// Note that exclusions don't contribute to the indices in [`Matches`][],
+    /// which always refer to the selectors added via [`Builder::add`], and
+    /// are not part of [`Matcher::selectors`]. When serialized, they follow
+    /// after all selectors, each prefixed with `!`.
+    ///
+    /// [`Matches`]: crate::id::matcher::Matches
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the given selector is invalid, or if a
+    /// component cannot successfully be parsed into a valid [`Glob`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Matcher;
+    ///
+    /// // Create matcher builder and add selector and exclusion
+    /// let mut builder = Matcher::builder();
+    /// builder.add(&"zrs:::::**/*.md:")?;
+    /// builder.add_exclusion(&"zrs:::::**/draft/**:")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_exclusion<T>(&mut self, selector: &T) -> Result<&mut Self>
+    where
+        T: TryIntoSelector,
+    {
+        let exclusions = self.exclusions.get_or_insert_default();
+        exclusions.add(selector)?;
+
         // Return builder for chaining
         Ok(self)
     }
 
+    /// Limits the number of brace expansions of selectors.
+    ///
+    /// Alternates like `{a,b}{c,d}` expand combinatorially when glob sets are
+    /// compiled, so selectors from untrusted input can lead to excessive time
+    /// and memory consumption. When a limit is set, [`Builder::build`] checks
+    /// the number of expansions of each component of all selectors, including
+    /// exclusions, before compiling, and rejects those exceeding the limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Matcher;
+    ///
+    /// // Create matcher builder and add selector
+    /// let mut builder = Matcher::builder();
+    /// builder.add(&"zrs:::::{a,b}/{c,d}/*.md:")?;
+    ///
+    /// // Limit number of brace expansions
+    /// builder.max_expansions(2);
+    /// assert!(builder.build().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn max_expansions(&mut self, limit: usize) -> &mut Self {
+        self.max_expansions = Some(limit);
+        self
+    }
+
     /// Builds the matcher.
     ///
     /// # Errors
     ///
     /// This method returns an error if the [`GlobSet`][] that is associated
-    /// with a component cannot be successfully built.
+    /// with a component cannot be successfully built, or if a selector exceeds
+    /// the limit set with [`Builder::max_expansions`].
     ///
     /// [`GlobSet`]: globset::GlobSet
     ///
@@ -181,6 +268,36 @@ impl Builder {
     /// # }
     /// ```
     pub fn build(self) -> Result<Matcher> {
+        if let Some(limit) = self.max_expansions {
+            for selector in &self.selectors {
+                let selector = selector.try_into_selector()?;
+                let count = [
+                    selector.provider(),
+                    selector.resource(),
+                    selector.variant(),
+                    selector.context(),
+                    selector.location(),
+                    selector.fragment(),
+                ]
+                .iter()
+                .flatten()
+                .map(|pattern| expansions(pattern))
+                .max()
+                .unwrap_or(1);
+
+                // Reject selector, if it exceeds the limit
+                if count > limit {
+                    Err(Error::Expansion {
+                        selector: selector.as_str().to_string(),
+                        count,
+                        limit,
+                    })?;
+                }
+            }
+        }
+
+        // Build matcher, passing the limit on to exclusions
+        let max_expansions = self.max_expansions;
         Ok(Matcher {
             provider: self.provider.build()?,
             resource: self.resource.build()?,
@@ -188,6 +305,14 @@ impl Builder {
             context: self.context.build()?,
             location: self.location.build()?,
             fragment: self.fragment.build()?,
+            selectors: self.selectors,
+            exclusions: self
+                .exclusions
+                .map(|mut builder| {
+                    builder.max_expansions = max_expansions;
+                    builder.build().map(Box::new)
+                })
+                .transpose()?,
         })
     }
 }
@@ -197,7 +322,7 @@ impl Builder {
 // ----------------------------------------------------------------------------
 
 /// Compiles a component for addition to the matcher.
-fn compile(opt: Option<&str>) -> Result<Option<Glob>> {
+pub(super) fn compile(opt: Option<&str>) -> Result<Option<Glob>> {
     if let Some(pattern) = opt {
         let mut builder = GlobBuilder::new(pattern);
         // We enable empty alternates to support patterns like "{,**/}*.md",
@@ -207,3 +332,55 @@ fn compile(opt: Option<&str>) -> Result<Option<Glob>> {
         Ok(None)
     }
 }
+
+/// Computes the number of brace expansions of a pattern.
+///
+/// Alternatives are summed up and consecutive alternates are multiplied, so
+/// `{a,b}{c,{d,e}}` yields 6 expansions. Escaped characters and characters in
+/// classes are skipped, and arithmetic saturates to prevent overflows.
+fn expansions(pattern: &str) -> usize {
+    let (count, _) = expand(&mut pattern.chars().peekable(), false);
+    count
+}
+
+/// Computes the number of brace expansions of a sequence.
+///
+/// The sequence ends at the end of the pattern or, if nested, at the next `,`
+/// or `}` of the enclosing alternate, which is returned alongside the count.
+fn expand(chars: &mut Peekable<Chars>, nested: bool) -> (usize, Option<char>) {
+    let mut count = 1_usize;
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => {
+                chars.next();
+            }
+
+            // Skip character classes, including a leading negation and `]`
+            '[' => {
+                chars.next_if(|&char| char == '!' || char == '^');
+                chars.next_if_eq(&']');
+                chars.find(|&char| char == ']');
+            }
+
+            // Sum up the expansions of all alternatives of an alternate
+            '{' => {
+                let mut sum = 0_usize;
+                loop {
+                    let (n, end) = expand(chars, true);
+                    sum = sum.saturating_add(n);
+                    if end != Some(',') {
+                        break;
+                    }
+                }
+                count = count.saturating_mul(sum);
+            }
+
+            // End of alternative in enclosing alternate
+            ',' | '}' if nested => return (count, Some(char)),
+            _ => {}
+        }
+    }
+
+    // End of pattern
+    (count, None)
+}
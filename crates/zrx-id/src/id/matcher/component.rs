@@ -59,13 +59,15 @@ impl Component {
     /// Empty patterns are considered wildcards and thus equivalent to `**`,
     /// which means they're always included in the match set. Additionally,
     /// all patterns matching the given path are included, reconstructed from
-    /// the internal mapping.
-    pub fn matches<S>(&self, path: S) -> Matches
+    /// the internal mapping. The given slots are cleared and used as a buffer
+    /// for the glob set, so allocations can be amortized across calls.
+    pub fn matches<S>(&self, path: S, slots: &mut Vec<usize>) -> Matches
     where
         S: AsRef<Path>,
     {
         let mut matches = self.matches.clone();
-        for index in self.globset.matches(path) {
+        self.globset.matches_into(path, slots);
+        for &index in slots.iter() {
             matches.insert(self.mapping[index]);
         }
 
@@ -44,6 +44,17 @@ pub enum Error {
     /// Identifier error.
     #[error(transparent)]
     Id(#[from] id::Error),
+
+    /// Expansion limit exceeded.
+    #[error("selector exceeds expansion limit of {limit}: {selector}")]
+    Expansion {
+        /// Selector.
+        selector: String,
+        /// Number of expansions.
+        count: usize,
+        /// Maximum number of expansions.
+        limit: usize,
+    },
 }
 
 // ----------------------------------------------------------------------------
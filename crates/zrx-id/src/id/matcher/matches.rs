@@ -26,8 +26,10 @@
 //! Match set.
 
 mod into_iter;
+mod iter;
 
 pub use into_iter::IntoIter;
+pub use iter::Iter;
 
 // ----------------------------------------------------------------------------
 // Structs
@@ -154,6 +156,9 @@ impl Matches {
 
     /// Computes the union with the given match set.
     ///
+    /// If the given match set has more blocks, the match set is grown, so no
+    /// matches are lost when match sets of different capacities are combined.
+    ///
     /// # Examples
     ///
     /// ```
@@ -168,6 +173,9 @@ impl Matches {
     /// assert_eq!(a, Matches::from_iter([0, 1, 2]));
     /// ```
     pub fn union(&mut self, other: &Self) {
+        if self.data.len() < other.data.len() {
+            self.data.resize(other.data.len(), 0);
+        }
         for (a, b) in self.data.iter_mut().zip(&other.data) {
             *a |= *b;
         }
@@ -175,6 +183,9 @@ impl Matches {
 
     /// Computes the intersection with the given match set.
     ///
+    /// Blocks beyond the capacity of the given match set are cleared, as the
+    /// given match set doesn't contain any of their matches.
+    ///
     /// # Examples
     ///
     /// ```
@@ -192,6 +203,9 @@ impl Matches {
         for (a, b) in self.data.iter_mut().zip(&other.data) {
             *a &= *b;
         }
+        if let Some(rest) = self.data.get_mut(other.data.len()..) {
+            rest.fill(0);
+        }
     }
 
     /// Returns whether both match sets have any matches in common.
@@ -217,6 +231,10 @@ impl Matches {
 
     /// Returns whether both match sets have all matches in common.
     ///
+    /// This method checks whether all matches of the match set are contained
+    /// in the given match set, which must also hold for blocks beyond the
+    /// capacity of the given match set, so they must not contain matches.
+    ///
     /// # Examples
     ///
     /// ```
@@ -234,6 +252,7 @@ impl Matches {
     pub fn has_all(&self, other: &Self) -> bool {
         let mut iter = self.data.iter().zip(&other.data);
         iter.all(|(a, b)| (*a & *b) == *a)
+            && self.data.iter().skip(other.data.len()).all(|&a| a == 0)
     }
 
     /// Resolve the block for the given match.
@@ -311,3 +330,94 @@ impl Default for Matches {
         Self::with_capacity(1)
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod contains {
+        use crate::id::matcher::Matches;
+
+        #[test]
+        fn handles_blocks() {
+            let matches = Matches::from_iter([0, 63, 64, 128]);
+            for index in [0, 63, 64, 128] {
+                assert!(matches.contains(index));
+            }
+            for index in [1, 62, 65, 127, 129] {
+                assert!(!matches.contains(index));
+            }
+        }
+    }
+
+    mod union {
+        use crate::id::matcher::Matches;
+
+        #[test]
+        fn handles_blocks() {
+            let mut a = Matches::from_iter([0, 63]);
+            a.union(&Matches::from_iter([64, 128]));
+            assert_eq!(a, Matches::from_iter([0, 63, 64, 128]));
+        }
+    }
+
+    mod intersect {
+        use crate::id::matcher::Matches;
+
+        #[test]
+        fn handles_blocks() {
+            let mut a = Matches::from_iter([0, 63, 64, 128]);
+            a.intersect(&Matches::from_iter([63, 64]));
+            assert_eq!(a.iter().collect::<Vec<_>>(), Vec::from([63, 64]));
+        }
+    }
+
+    mod has_any {
+        use crate::id::matcher::Matches;
+
+        #[test]
+        fn handles_blocks() {
+            let a = Matches::from_iter([0, 128]);
+            assert!(a.has_any(&Matches::from_iter([64, 128])));
+            assert!(!a.has_any(&Matches::from_iter([63, 64])));
+        }
+    }
+
+    mod has_all {
+        use crate::id::matcher::Matches;
+
+        #[test]
+        fn handles_blocks() {
+            let a = Matches::from_iter([0, 63, 64, 128]);
+            assert!(a.has_all(&Matches::from_iter([0, 63, 64, 128, 129])));
+            assert!(!a.has_all(&Matches::from_iter([0, 63, 64])));
+            assert!(!a.has_all(&Matches::from_iter([0, 63])));
+        }
+    }
+
+    mod iter {
+        use crate::id::matcher::Matches;
+
+        #[test]
+        fn handles_blocks() {
+            let matches = Matches::from_iter([0, 3, 64]);
+            assert_eq!(
+                matches.iter().collect::<Vec<_>>(), // fmt
+                Vec::from([0, 3, 64])
+            );
+            assert_eq!(matches.len(), 3);
+            assert!(!matches.is_empty());
+        }
+
+        #[test]
+        fn handles_empty() {
+            let matches = Matches::with_capacity(0);
+            assert_eq!(matches.iter().next(), None);
+            assert_eq!(matches.len(), 0);
+            assert!(matches.is_empty());
+        }
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright (c) 2025-2026 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// All contributions are certified under the DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Iterator over match set.
+
+use super::Matches;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Iterator over match set.
+pub struct Iter<'a> {
+    /// Blocks of bits.
+    data: &'a [u64],
+    /// Current block index.
+    index: usize,
+    /// Current block.
+    block: u64,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Matches {
+    /// Creates an iterator over the match set.
+    ///
+    /// Matches are yielded in ascending order, which allows to determine which
+    /// selectors of a [`Matcher`][] matched, e.g., for logging purposes.
+    ///
+    /// [`Matcher`]: crate::id::matcher::Matcher
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_id::Matches;
+    ///
+    /// // Create match set from iterator
+    /// let matches = Matches::from_iter([0, 1]);
+    ///
+    /// // Create iterator over match set
+    /// for index in matches.iter() {
+    ///     println!("{index:?}");
+    /// }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            data: &self.data,
+            index: 0,
+            block: self.data.first().copied().unwrap_or(0),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<'a> IntoIterator for &'a Matches {
+    type Item = usize;
+    type IntoIter = Iter<'a>;
+
+    /// Creates an iterator over the match set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_id::Matches;
+    ///
+    /// // Create match set from iterator
+    /// let matches = Matches::from_iter([0, 1]);
+    ///
+    /// // Create iterator over match set
+    /// for index in &matches {
+    ///     println!("{index:?}");
+    /// }
+    /// ```
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl Iterator for Iter<'_> {
+    type Item = usize;
+
+    /// Returns the next match.
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.block != 0 {
+                let num = self.block.trailing_zeros() as usize;
+
+                // Clear the lowest bit and return it
+                self.block &= self.block - 1;
+                return Some(self.index << 6 | num);
+            }
+
+            // Move to the next block
+            self.index += 1;
+
+            // If all blocks are exhausted, we're done
+            if self.index >= self.data.len() {
+                return None;
+            }
+
+            // Update the current block to the next block
+            self.block = self.data[self.index];
+        }
+    }
+}
@@ -371,3 +371,40 @@ impl fmt::Debug for Selector {
             .finish()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod components {
+        use crate::id::matcher::selector::Selector;
+        use crate::id::Result;
+
+        #[test]
+        fn handles_selector() -> Result {
+            let selector: Selector = "zrs:file:::docs:**/*.md:".parse()?;
+            assert_eq!(selector.provider().as_deref(), Some("file"));
+            assert_eq!(selector.resource().as_deref(), None);
+            assert_eq!(selector.variant().as_deref(), None);
+            assert_eq!(selector.context().as_deref(), Some("docs"));
+            assert_eq!(selector.location().as_deref(), Some("**/*.md"));
+            assert_eq!(selector.fragment().as_deref(), None);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_empty() -> Result {
+            let selector: Selector = "zrs::::::".parse()?;
+            assert_eq!(selector.provider(), None);
+            assert_eq!(selector.resource(), None);
+            assert_eq!(selector.variant(), None);
+            assert_eq!(selector.context(), None);
+            assert_eq!(selector.location(), None);
+            assert_eq!(selector.fragment(), None);
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,193 @@
+// Copyright (c) 2025-2026 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// All contributions are certified under the DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Identifier parts.
+
+use super::error::{Error, Result};
+use super::Id;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Identifier parts.
+///
+/// Parts are a structured representation of an [`Id`] with owned components,
+/// which is useful at boundaries like FFI or serialization, as converting an
+/// identifier into parts and back doesn't require parsing. Optional components
+/// are represented as [`None`], and [`Some`] with an empty string is treated
+/// just like [`None`] when converting parts into an identifier.
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_id::{Id, IdParts};
+///
+/// // Create identifier from parts
+/// let id = Id::try_from(IdParts {
+///     provider: String::from("file"),
+///     context: String::from("docs"),
+///     location: String::from("index.md"),
+///     ..IdParts::default()
+/// })?;
+/// assert_eq!(id.as_str(), "zri:file:::docs:index.md:");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IdParts {
+    /// Component `provider`.
+    pub provider: String,
+    /// Component `resource`, if any.
+    pub resource: Option<String>,
+    /// Component `variant`, if any.
+    pub variant: Option<String>,
+    /// Component `context`.
+    pub context: String,
+    /// Component `location`.
+    pub location: String,
+    /// Component `fragment`, if any.
+    pub fragment: Option<String>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Id {
+    /// Converts the identifier into parts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Id;
+    ///
+    /// // Create identifier from string
+    /// let id: Id = "zri:file:::docs:index.md:".parse()?;
+    ///
+    /// // Convert identifier into parts
+    /// let parts = id.to_parts();
+    /// assert_eq!(parts.location, "index.md");
+    /// assert_eq!(parts.fragment, None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_parts(&self) -> IdParts {
+        IdParts {
+            provider: self.provider().into_owned(),
+            resource: self.resource().map(Into::into),
+            variant: self.variant().map(Into::into),
+            context: self.context().into_owned(),
+            location: self.location().into_owned(),
+            fragment: self.fragment().map(Into::into),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl TryFrom<IdParts> for Id {
+    type Error = Error;
+
+    /// Attempts to create an identifier from parts.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Component`] if any of the `provider`,
+    /// `context` or `location` components are empty, and [`Error::Format`]
+    /// if a component is invalid, e.g., because it contains a backslash.
+    fn try_from(parts: IdParts) -> Result<Self> {
+        let mut builder = Id::builder();
+        builder.set_provider(parts.provider);
+        if let Some(resource) = parts.resource {
+            builder.set_resource(resource);
+        }
+        if let Some(variant) = parts.variant {
+            builder.set_variant(variant);
+        }
+        builder.set_context(parts.context);
+        builder.set_location(parts.location);
+        if let Some(fragment) = parts.fragment {
+            builder.set_fragment(fragment);
+        }
+        builder.build()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod to_parts {
+        use crate::id::{Id, Result};
+
+        #[test]
+        fn handles_round_trip() -> Result {
+            for value in [
+                "zri:file:::docs:index.md:",
+                "zri:git:main:en:docs:guide/index.md:anchor",
+            ] {
+                let id: Id = value.parse()?;
+                assert_eq!(Id::try_from(id.to_parts())?, id);
+            }
+            Ok(())
+        }
+    }
+
+    mod try_from {
+        use crate::id::{Error, Id, IdParts};
+
+        #[test]
+        fn handles_backslash() {
+            let res = Id::try_from(IdParts {
+                provider: String::from("file"),
+                context: String::from("docs"),
+                location: String::from("a\\b.md"),
+                ..IdParts::default()
+            });
+            assert!(matches!(res, Err(Error::Format(_))));
+        }
+
+        #[test]
+        fn handles_missing() {
+            let res = Id::try_from(IdParts {
+                provider: String::from("file"),
+                location: String::from("index.md"),
+                ..IdParts::default()
+            });
+            assert!(matches!(res, Err(Error::Component("context"))));
+        }
+    }
+}
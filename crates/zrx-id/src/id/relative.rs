@@ -0,0 +1,169 @@
+// Copyright (c) 2025-2026 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// All contributions are certified under the DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Context-relative identifier.
+
+use super::Id;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Context-relative identifier.
+///
+/// This data type holds all components of an [`Id`] except for the `context`,
+/// which is required for identifiers, and thus can't be left empty. It allows
+/// to key artifacts across contexts, e.g., to compare files in a source and an
+/// output directory, as identifiers that only differ in their `context` yield
+/// equal context-relative identifiers.
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_id::Id;
+///
+/// // Create identifiers from strings
+/// let a: Id = "zri:file:::docs:index.md:".parse()?;
+/// let b: Id = "zri:file:::site:index.md:".parse()?;
+///
+/// // Compare identifiers regardless of context
+/// assert_eq!(a.strip_context(), b.strip_context());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ContextRelative {
+    /// Component `provider`.
+    provider: String,
+    /// Component `resource`, if any.
+    resource: Option<String>,
+    /// Component `variant`, if any.
+    variant: Option<String>,
+    /// Component `location`.
+    location: String,
+    /// Component `fragment`, if any.
+    fragment: Option<String>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Id {
+    /// Returns the context-relative identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Id;
+    ///
+    /// // Create identifier from string
+    /// let id: Id = "zri:file:::docs:index.md:".parse()?;
+    ///
+    /// // Obtain context-relative identifier
+    /// let relative = id.strip_context();
+    /// assert_eq!(relative.location(), "index.md");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn strip_context(&self) -> ContextRelative {
+        ContextRelative {
+            provider: self.provider().into_owned(),
+            resource: self.resource().map(Into::into),
+            variant: self.variant().map(Into::into),
+            location: self.location().into_owned(),
+            fragment: self.fragment().map(Into::into),
+        }
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl ContextRelative {
+    /// Returns the `provider` component.
+    #[inline]
+    pub fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    /// Returns the `resource` component, if any.
+    #[inline]
+    pub fn resource(&self) -> Option<&str> {
+        self.resource.as_deref()
+    }
+
+    /// Returns the `variant` component, if any.
+    #[inline]
+    pub fn variant(&self) -> Option<&str> {
+        self.variant.as_deref()
+    }
+
+    /// Returns the `location` component.
+    #[inline]
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    /// Returns the `fragment` component, if any.
+    #[inline]
+    pub fn fragment(&self) -> Option<&str> {
+        self.fragment.as_deref()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod strip_context {
+        use crate::id::{Id, Result};
+
+        #[test]
+        fn handles_contexts() -> Result {
+            let a: Id = "zri:git:main:en:docs:index.md:anchor".parse()?;
+            let b: Id = "zri:git:main:en:site:index.md:anchor".parse()?;
+            assert_ne!(a, b);
+            assert_eq!(a.strip_context(), b.strip_context());
+            Ok(())
+        }
+
+        #[test]
+        fn handles_components() -> Result {
+            let a: Id = "zri:git:main:en:docs:index.md:".parse()?;
+            let b: Id = "zri:git:main:de:site:index.md:".parse()?;
+            assert_ne!(a.strip_context(), b.strip_context());
+            assert_eq!(a.strip_context().variant(), Some("en"));
+            assert_eq!(a.strip_context().fragment(), None);
+            Ok(())
+        }
+    }
+}
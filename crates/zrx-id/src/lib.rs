@@ -33,4 +33,7 @@ pub use id::format;
 pub use id::matcher::selector::{Selector, TryIntoSelector};
 pub use id::matcher::{self, Matcher, Matches};
 pub use id::uri;
-pub use id::{Builder, Error, Id, Result, TryIntoId};
+pub use id::{
+    Builder, ComponentMask, ContextRelative, Error, Id, IdParts, Result,
+    TryIntoId,
+};
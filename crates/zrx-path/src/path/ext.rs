@@ -42,6 +42,12 @@ pub trait PathExt {
     fn relative_to<P>(&self, base: P) -> PathBuf
     where
         P: AsRef<Path>;
+
+    /// Returns an iterator over the ancestors of the path.
+    fn ancestors_relative(&self) -> impl Iterator<Item = PathBuf>;
+
+    /// Normalizes the path and renders it with forward slashes.
+    fn to_uri_path(&self) -> String;
 }
 
 // ----------------------------------------------------------------------------
@@ -89,6 +95,46 @@ impl PathExt for Path {
     {
         transform::relative_to(self, base)
     }
+
+    /// Returns an iterator over the ancestors of the path.
+    ///
+    /// For more information, see [`transform::ancestors`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    /// use zrx_path::PathExt;
+    ///
+    /// // Iterate over ancestors of path
+    /// let path = Path::new("docs/index.md");
+    /// let mut iter = path.ancestors_relative();
+    /// assert_eq!(iter.next(), Some(PathBuf::from("docs/")));
+    /// assert_eq!(iter.next(), Some(PathBuf::from("")));
+    /// ```
+    #[inline]
+    fn ancestors_relative(&self) -> impl Iterator<Item = PathBuf> {
+        transform::ancestors(self)
+    }
+
+    /// Normalizes the path and renders it with forward slashes.
+    ///
+    /// For more information, see [`transform::to_uri_path`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use zrx_path::PathExt;
+    ///
+    /// // Render path with forward slashes
+    /// let path = Path::new("a\\b\\..\\c").to_uri_path();
+    /// assert_eq!(path, "a/c");
+    /// ```
+    #[inline]
+    fn to_uri_path(&self) -> String {
+        transform::to_uri_path(self)
+    }
 }
 
 impl PathExt for PathBuf {
@@ -132,4 +178,44 @@ impl PathExt for PathBuf {
     {
         transform::relative_to(self, base)
     }
+
+    /// Returns an iterator over the ancestors of the path.
+    ///
+    /// For more information, see [`transform::ancestors`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    /// use zrx_path::PathExt;
+    ///
+    /// // Iterate over ancestors of path
+    /// let path = PathBuf::from("docs/index.md");
+    /// let mut iter = path.ancestors_relative();
+    /// assert_eq!(iter.next(), Some(PathBuf::from("docs/")));
+    /// assert_eq!(iter.next(), Some(PathBuf::from("")));
+    /// ```
+    #[inline]
+    fn ancestors_relative(&self) -> impl Iterator<Item = PathBuf> {
+        transform::ancestors(self)
+    }
+
+    /// Normalizes the path and renders it with forward slashes.
+    ///
+    /// For more information, see [`transform::to_uri_path`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    /// use zrx_path::PathExt;
+    ///
+    /// // Render path with forward slashes
+    /// let path = PathBuf::from("a\\b\\..\\c").to_uri_path();
+    /// assert_eq!(path, "a/c");
+    /// ```
+    #[inline]
+    fn to_uri_path(&self) -> String {
+        transform::to_uri_path(self)
+    }
 }
@@ -26,7 +26,8 @@
 //! Path transformations.
 
 use std::ffi::OsStr;
-use std::path::{Component, Path, PathBuf};
+use std::iter::successors;
+use std::path::{is_separator, Component, Path, PathBuf};
 
 // ----------------------------------------------------------------------------
 // Functions
@@ -62,28 +63,7 @@ where
 
     // Analyze all components of the given path, and normalize all `.` and `..`
     // components, so that we get a comparable path, e.g., for relative URLs
-    let mut stack = Vec::new();
-    for component in path.components() {
-        match component {
-            Component::CurDir => {}
-            Component::ParentDir => match stack.last() {
-                // If the current component is `..`, and we have a component on
-                // the stack that resembles a normal path, remove the parent
-                Some(Component::Normal(_)) => {
-                    stack.pop();
-                }
-                // If the current component is `..`, and the last component is
-                // another `..` component, or the stack is empty, add `..`
-                Some(Component::ParentDir) | None => {
-                    stack.push(Component::ParentDir);
-                }
-                // Otherwise just ignore `..`, which is the case when the prior
-                // component is either a root or a prefix component
-                Some(_) => {}
-            },
-            _ => stack.push(component),
-        }
-    }
+    let mut stack = normalize_components(path.components());
 
     // Trailing slashes must be preserved, which Rust just doesn't when paths
     // are constructed, since relative path computation would be incorrect
@@ -95,6 +75,94 @@ where
     stack.into_iter().collect()
 }
 
+/// Normalizes the given absolute or relative path, folding its case.
+///
+/// This method normalizes the path with [`normalize`], and then converts all
+/// ASCII characters to lowercase, while leaving non-ASCII characters as is.
+/// It's meant for deriving comparison or hash keys on case-insensitive file
+/// systems, where paths that only differ in case refer to the same file, and
+/// must not be used for display, as the original case is lost.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+/// use zrx_path::transform::normalize_case_fold;
+///
+/// // Normalize path and fold case
+/// let path = normalize_case_fold("A/../B.MD");
+/// assert_eq!(path, PathBuf::from("b.md"));
+/// ```
+pub fn normalize_case_fold<P>(path: P) -> PathBuf
+where
+    P: AsRef<Path>,
+{
+    let mut path = normalize(path).into_os_string();
+    path.make_ascii_lowercase();
+    PathBuf::from(path)
+}
+
+/// Returns whether the given absolute or relative path is normalized.
+///
+/// This method checks whether [`normalize`] would leave the given path as is,
+/// which allows to skip normalization and the allocation it entails. A path is
+/// considered normalized if it contains neither `.` components, nor redundant
+/// slashes, nor `..` components that could be collapsed, i.e., `..` is only
+/// allowed at the start of relative paths. As with [`normalize`], a trailing
+/// slash is considered part of the normalized path.
+///
+/// # Examples
+///
+/// ```
+/// use zrx_path::transform::is_normalized;
+///
+/// // Check whether paths are normalized
+/// assert!(is_normalized("a/b"));
+/// assert!(!is_normalized("a/../b"));
+/// ```
+pub fn is_normalized<P>(path: P) -> bool
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let bytes = path.as_os_str().as_encoded_bytes();
+
+    // Split the path into segments, which is necessary since Rust's component
+    // iterator already skips `.` components and redundant slashes, and skip
+    // the empty segment in front of the root of absolute paths
+    let mut iter = bytes
+        .split(|&byte| is_separator(char::from(byte)))
+        .peekable();
+    if path.has_root() {
+        iter.next();
+    }
+
+    // Analyze all segments, where `..` components are only allowed as long as
+    // they're leading in relative paths, as they can't be collapsed otherwise
+    let mut leading = !path.has_root();
+    while let Some(segment) = iter.next() {
+        match segment {
+            // Empty segments denote redundant slashes, except for the last one,
+            // which denotes a trailing slash that is preserved by normalization
+            b"" => {
+                if iter.peek().is_some() {
+                    return false;
+                }
+            }
+            b"." => return false,
+            b".." => {
+                if !leading {
+                    return false;
+                }
+            }
+            _ => leading = false,
+        }
+    }
+
+    // No redundant components found
+    true
+}
+
 /// Creates a relative path from the given base path.
 ///
 /// If the base path ends with a slash, its last component must be treated as
@@ -102,6 +170,10 @@ where
 /// path computation, as a folder at the end of the base path might require an
 /// additional `..` component to be added to the relative path.
 ///
+/// A trailing slash of the path is preserved in the relative path, independent
+/// of whether the base path ends with a slash, so joining the folder of the
+/// base path with the relative path and normalizing it yields the path.
+///
 /// # Examples
 ///
 /// ```
@@ -124,17 +196,6 @@ where
     let target = path.components().collect::<Vec<_>>();
     let mut source = base.components().collect::<Vec<_>>();
 
-    // Start by searching for the common prefix of both paths, since those are
-    // the parts that don't need to be traversed, and can thus be excluded
-    let mut prefix = 0;
-    while prefix < target.len() && prefix < source.len() {
-        if target[prefix] == source[prefix] {
-            prefix += 1;
-        } else {
-            break;
-        }
-    }
-
     // If the base path does not end in a trailing slash, it means we need to
     // compute the relative path from the folder the last component is in, so
     // we remove the last path segment from the base path. This also means all
@@ -143,6 +204,12 @@ where
         source.pop();
     }
 
+    // Next, search for the common prefix of both paths, since those are the
+    // parts that don't need to be traversed, and can thus be excluded. This
+    // must happen after removing the file component, as it's not part of the
+    // folder we're computing the relative path from.
+    let prefix = prefix_len(&target, &source);
+
     // Next, for each remaining component in the base path, add the same number
     // of `..` components to the relative path, as this is the number of parent
     // directories we need to traverse until we reach the common prefix, from
@@ -158,8 +225,8 @@ where
         stack.push(component);
     }
 
-    // In case the base and target path are the same, we do not need to move at
-    // all, which we denote by adding a `.` component
+    // In case the target path is the folder of the base path, we do not need to
+    // move at all, which we denote by adding a `.` component
     if stack.is_empty() {
         stack.push(Component::CurDir);
     }
@@ -174,6 +241,345 @@ where
     stack.into_iter().collect()
 }
 
+/// Returns the common prefix of the given absolute or relative paths.
+///
+/// Both paths are normalized before computing the longest sequence of shared
+/// components, so the result is a normalized path as well. If the paths don't
+/// share any components, an empty path is returned. Note that the common
+/// prefix never carries a trailing slash, as it's computed from components.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+/// use zrx_path::transform::common_prefix;
+///
+/// // Compute common prefix of paths
+/// let path = common_prefix("a/b/c", "a/b/d");
+/// assert_eq!(path, PathBuf::from("a/b"));
+/// ```
+pub fn common_prefix<P, Q>(a: P, b: Q) -> PathBuf
+where
+    P: AsRef<Path>,
+    Q: AsRef<Path>,
+{
+    let a = normalize(a);
+    let b = normalize(b);
+
+    // Collect all components from both paths
+    let x = a.components().collect::<Vec<_>>();
+    let y = b.components().collect::<Vec<_>>();
+
+    // Collect shared components into path
+    x[..prefix_len(&x, &y)].iter().collect()
+}
+
+/// Strips the given prefix from the given path after normalizing both.
+///
+/// Unlike [`Path::strip_prefix`], both paths are normalized before stripping,
+/// so that paths only differing in redundant components, e.g., `a/./b` and
+/// `a/b`, are considered equal. If the prefix isn't an ancestor of the path
+/// after normalization, [`None`] is returned. A trailing slash of the path is
+/// preserved, and if both paths are equal, an empty path is returned.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+/// use zrx_path::transform::strip_prefix_normalized;
+///
+/// // Strip prefix from path
+/// let path = strip_prefix_normalized("a/./b/c", "a/b");
+/// assert_eq!(path, Some(PathBuf::from("c")));
+/// ```
+pub fn strip_prefix_normalized<P, Q>(path: P, prefix: Q) -> Option<PathBuf>
+where
+    P: AsRef<Path>,
+    Q: AsRef<Path>,
+{
+    let path = normalize(path);
+    let prefix = normalize(prefix);
+
+    // Collect all components from both paths
+    let target = path.components().collect::<Vec<_>>();
+    let source = prefix.components().collect::<Vec<_>>();
+
+    // Ensure that all components of the prefix are shared with the path
+    let n = prefix_len(&target, &source);
+    if n < source.len() {
+        return None;
+    }
+
+    // Collect remaining components into path, preserving trailing slashes
+    let mut stack = target[n..].to_vec();
+    if path.to_string_lossy().ends_with(['/', '\\']) {
+        stack.push(Component::Normal(OsStr::new("")));
+    }
+    Some(stack.into_iter().collect())
+}
+
+/// Returns an iterator over the ancestors of the given path.
+///
+/// The path is normalized first, and then each folder containing it is yielded
+/// as a normalized path with a trailing slash, starting with the innermost one.
+/// Iteration stops at the relative root, i.e., the empty path, or at the root
+/// of absolute paths, as well as at leading `..` components, as those can't
+/// be traversed any further.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+/// use zrx_path::transform::ancestors;
+///
+/// // Iterate over ancestors of path
+/// let mut iter = ancestors("docs/guide/index.md");
+/// assert_eq!(iter.next(), Some(PathBuf::from("docs/guide/")));
+/// assert_eq!(iter.next(), Some(PathBuf::from("docs/")));
+/// assert_eq!(iter.next(), Some(PathBuf::from("")));
+/// assert_eq!(iter.next(), None);
+/// ```
+pub fn ancestors<P>(path: P) -> impl Iterator<Item = PathBuf>
+where
+    P: AsRef<Path>,
+{
+    let path = normalize(path);
+
+    // Only ascend from paths ending in a normal component, since neither the
+    // root, nor the relative root, nor `..` components have a parent
+    let iter =
+        successors(Some(path), |path| match path.components().next_back() {
+            Some(Component::Normal(_)) => path.parent().map(PathBuf::from),
+            _ => None,
+        });
+
+    // Skip the path itself, and ensure all ancestors end in a trailing slash
+    iter.skip(1).map(|mut path| {
+        path.push("");
+        path
+    })
+}
+
+/// Normalizes the given path and renders it with forward slashes.
+///
+/// Backslashes are treated as separators independent of the platform, so that
+/// Windows-style and Unix-style paths yield the same result, which makes this
+/// function suitable for building URLs from arbitrary paths. As with
+/// [`normalize`], `.` and `..` components are collapsed, and trailing slashes
+/// are preserved. Note that no percent-encoding is applied.
+///
+/// # Examples
+///
+/// ```
+/// use zrx_path::transform::to_uri_path;
+///
+/// // Render path with forward slashes
+/// let path = to_uri_path("a\\b\\..\\c");
+/// assert_eq!(path, "a/c");
+/// ```
+pub fn to_uri_path<P>(path: P) -> String
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref().to_string_lossy().replace('\\', "/");
+    normalize(path).to_string_lossy().replace('\\', "/")
+}
+
+/// Returns the directory depth of the given path.
+///
+/// The path is normalized first, and then the number of folders it's nested in
+/// is counted, i.e., the number of `..` components needed to reach the relative
+/// root from its folder. Consistent with [`relative_to`], a path ending with a
+/// slash is treated as a folder, which adds a level of directory traversal.
+/// Leading `..` components are subtracted, and the depth is clamped at zero.
+///
+/// # Examples
+///
+/// ```
+/// use zrx_path::transform::depth;
+///
+/// // Compute depth of file and folder
+/// assert_eq!(depth("a/b/c.md"), 2);
+/// assert_eq!(depth("a/b/"), 2);
+/// ```
+pub fn depth<P>(path: P) -> usize
+where
+    P: AsRef<Path>,
+{
+    let path = normalize(path);
+
+    // Count normal and `..` components, as the latter can only be leading in
+    // normalized paths, and thus ascend above the relative root
+    let mut normal: usize = 0;
+    let mut parent = 0;
+    for component in path.components() {
+        match component {
+            Component::Normal(_) => normal += 1,
+            Component::ParentDir => parent += 1,
+            _ => {}
+        }
+    }
+
+    // If the path doesn't end in a trailing slash, and its last component is a
+    // normal component, it's a file, which doesn't add a level of traversal
+    let is_file =
+        matches!(path.components().next_back(), Some(Component::Normal(_)))
+            && !path.to_string_lossy().ends_with(['/', '\\']);
+    if is_file {
+        normal -= 1;
+    }
+
+    // Clamp depth at the relative root
+    normal.saturating_sub(parent)
+}
+
+/// Joins the given segment onto the base path and normalizes the result.
+///
+/// This is equivalent to normalizing the result of [`Path::join`], but avoids
+/// allocating the intermediate path, since components of both paths are
+/// normalized in a single pass. The base path is always treated as a folder,
+/// so that `..` components of the segment ascend from it, and if the segment
+/// is absolute, it replaces the base path. A trailing slash of the segment is
+/// preserved, consistent with [`normalize`], and an empty segment marks the
+/// base path as a folder, since [`Path::join`] appends a trailing slash.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+/// use zrx_path::transform::join_normalized;
+///
+/// // Join segment onto base path
+/// let path = join_normalized("a/b/", "../c");
+/// assert_eq!(path, PathBuf::from("a/c"));
+/// ```
+pub fn join_normalized<P, Q>(base: P, segment: Q) -> PathBuf
+where
+    P: AsRef<Path>,
+    Q: AsRef<Path>,
+{
+    let base = base.as_ref();
+    let segment = segment.as_ref();
+
+    // Absolute segments replace the base path, just like with `Path::join`, so
+    // we only need to consider the components of the base path otherwise
+    let mut stack = if segment.has_root() {
+        normalize_components(segment.components())
+    } else {
+        normalize_components(base.components().chain(segment.components()))
+    };
+
+    // Trailing slashes of the segment must be preserved, as with normalize,
+    // and joining an empty segment onto a non-empty base path adds one
+    let is_folder = if segment.as_os_str().is_empty() {
+        !base.as_os_str().is_empty()
+    } else {
+        segment.to_string_lossy().ends_with(['/', '\\'])
+    };
+    if is_folder {
+        stack.push(Component::Normal(OsStr::new("")));
+    }
+
+    // Collect components into path
+    stack.into_iter().collect()
+}
+
+/// Normalizes the given path and ensures that it ends with a trailing slash.
+///
+/// Consistent with [`relative_to`], a trailing slash marks the last component
+/// of a path as a folder, so this function clarifies intent at call sites that
+/// must treat a path as a folder. The path is normalized first, which collapses
+/// redundant slashes, so exactly one trailing slash is added. Empty paths are
+/// returned as is, as they denote the relative root.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+/// use zrx_path::transform::ensure_trailing_slash;
+///
+/// // Ensure path ends with trailing slash
+/// let path = ensure_trailing_slash("a/b");
+/// assert_eq!(path, PathBuf::from("a/b/"));
+/// ```
+pub fn ensure_trailing_slash<P>(path: P) -> PathBuf
+where
+    P: AsRef<Path>,
+{
+    let mut stack = normalize_components(path.as_ref().components());
+
+    // Add an empty component to denote the trailing slash, unless the path is
+    // empty or only consists of a root, which already ends with a slash
+    let last = stack.last();
+    if matches!(last, Some(Component::Normal(_) | Component::ParentDir)) {
+        stack.push(Component::Normal(OsStr::new("")));
+    }
+
+    // Collect components into path
+    stack.into_iter().collect()
+}
+
+/// Normalizes the given path and strips its trailing slash, if any.
+///
+/// This is the inverse of [`ensure_trailing_slash`], which marks the last
+/// component of a path as a file. The path is normalized first, so redundant
+/// slashes are collapsed as well. Note that the root of an absolute path is
+/// preserved, as it's a component and not a trailing slash.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+/// use zrx_path::transform::strip_trailing_slash;
+///
+/// // Strip trailing slash from path
+/// let path = strip_trailing_slash("a/b/");
+/// assert_eq!(path, PathBuf::from("a/b"));
+/// ```
+pub fn strip_trailing_slash<P>(path: P) -> PathBuf
+where
+    P: AsRef<Path>,
+{
+    normalize_components(path.as_ref().components())
+        .into_iter()
+        .collect()
+}
+
+/// Normalizes the given components, collapsing `.` and `..` components.
+fn normalize_components<'a, I>(components: I) -> Vec<Component<'a>>
+where
+    I: IntoIterator<Item = Component<'a>>,
+{
+    let mut stack = Vec::new();
+    for component in components {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match stack.last() {
+                // If the current component is `..`, and we have a component on
+                // the stack that resembles a normal path, remove the parent
+                Some(Component::Normal(_)) => {
+                    stack.pop();
+                }
+                // If the current component is `..`, and the last component is
+                // another `..` component, or the stack is empty, add `..`
+                Some(Component::ParentDir) | None => {
+                    stack.push(Component::ParentDir);
+                }
+                // Otherwise just ignore `..`, which is the case when the prior
+                // component is either a root or a prefix component
+                Some(_) => {}
+            },
+            _ => stack.push(component),
+        }
+    }
+    stack
+}
+
+/// Returns the number of leading components shared by both slices.
+fn prefix_len(a: &[Component], b: &[Component]) -> usize {
+    a.iter().zip(b).take_while(|(x, y)| x == y).count()
+}
+
 // ----------------------------------------------------------------------------
 // Tests
 // ----------------------------------------------------------------------------
@@ -332,64 +738,171 @@ mod tests {
         }
     }
 
-    mod relative_to {
-        use std::path::Path;
+    mod normalize_case_fold {
+        use std::path::PathBuf;
 
-        use crate::path::transform::relative_to;
+        use crate::path::transform::{normalize, normalize_case_fold};
 
         #[test]
-        fn handles_current() {
-            assert_eq!(relative_to("a/b", "a/b"), Path::new("."));
+        fn handles_case() {
+            assert_eq!(
+                normalize_case_fold("A/B.MD"), // fmt
+                normalize_case_fold("a/b.md")
+            );
+            assert_ne!(normalize("A/B.MD"), normalize("a/b.md"));
         }
 
         #[test]
-        fn handles_nested() {
-            assert_eq!(relative_to("a/b", "a"), Path::new("b"));
+        fn handles_dotdot() {
+            assert_eq!(
+                normalize_case_fold("A/./C/../B.MD"),
+                PathBuf::from("a/b.md")
+            );
         }
 
         #[test]
-        fn handles_nested_extension() {
-            assert_eq!(relative_to("a/b.ext", "a"), Path::new("b.ext"));
+        fn handles_non_ascii() {
+            assert_eq!(normalize_case_fold("Ä/Ö.MD"), PathBuf::from("Ä/Ö.md"));
         }
 
         #[test]
-        fn handles_nested_folder() {
-            assert_eq!(relative_to("a/b/", "a"), Path::new("b/"));
+        fn handles_folder() {
+            assert_eq!(normalize_case_fold("A/B/"), PathBuf::from("a/b/"));
         }
+    }
+
+    mod is_normalized {
+        use crate::path::transform::is_normalized;
 
         #[test]
-        fn handles_parent() {
-            assert_eq!(relative_to("a", "a/b"), Path::new("."));
+        fn handles_normalized() {
+            assert!(is_normalized("a/b/c"));
         }
 
         #[test]
-        fn handles_parent_extension() {
-            assert_eq!(relative_to("a", "a/b.ext"), Path::new("."));
+        fn handles_normalized_folder() {
+            assert!(is_normalized("a/b/"));
         }
 
         #[test]
-        fn handles_parent_folder() {
-            assert_eq!(relative_to("a", "a/b/"), Path::new(".."));
+        fn handles_dot() {
+            assert!(!is_normalized("a/./b"));
         }
 
         #[test]
-        fn handles_sibling() {
-            assert_eq!(relative_to("a/b", "a/c"), Path::new("b"));
+        fn handles_dot_leading() {
+            assert!(!is_normalized("./a/b"));
         }
 
         #[test]
-        fn handles_sibling_folder() {
-            assert_eq!(relative_to("a/b", "a/c/"), Path::new("../b"));
+        fn handles_dotdot() {
+            assert!(!is_normalized("a/../b"));
         }
 
         #[test]
-        fn handles_dotdot() {
-            assert_eq!(relative_to(".", ".."), Path::new("."));
+        fn handles_dotdot_leading() {
+            assert!(is_normalized("../../a/b"));
         }
 
         #[test]
-        fn handles_dotdot_nested() {
-            assert_eq!(relative_to("a", ".."), Path::new("a"));
+        fn handles_slashes() {
+            assert!(!is_normalized("a//b"));
+        }
+
+        #[test]
+        fn handles_folder_slashes() {
+            assert!(!is_normalized("a/b//"));
+        }
+
+        #[test]
+        fn handles_empty() {
+            assert!(is_normalized(""));
+        }
+
+        #[test]
+        fn handles_absolute() {
+            assert!(is_normalized("/a/b"));
+        }
+
+        #[test]
+        fn handles_absolute_root() {
+            assert!(is_normalized("/"));
+        }
+
+        #[test]
+        fn handles_absolute_dotdot_leading() {
+            assert!(!is_normalized("/../a"));
+        }
+    }
+
+    mod relative_to {
+        use std::ffi::OsString;
+        use std::path::Path;
+
+        use crate::path::transform::{normalize, relative_to};
+
+        #[test]
+        fn handles_current() {
+            assert_eq!(relative_to("a/b", "a/b"), Path::new("b"));
+            assert_eq!(resolve("a/b", "a/b"), normalize("a/b").as_os_str());
+        }
+
+        #[test]
+        fn handles_current_folder() {
+            assert_eq!(relative_to("a/b/", "a/b"), Path::new("b/"));
+            assert_eq!(resolve("a/b/", "a/b"), normalize("a/b/").as_os_str());
+        }
+
+        #[test]
+        fn handles_nested() {
+            assert_eq!(relative_to("a/b", "a"), Path::new("a/b"));
+            assert_eq!(resolve("a/b", "a"), normalize("a/b").as_os_str());
+        }
+
+        #[test]
+        fn handles_nested_extension() {
+            assert_eq!(relative_to("a/b.ext", "a"), Path::new("a/b.ext"));
+        }
+
+        #[test]
+        fn handles_nested_folder() {
+            assert_eq!(relative_to("a/b/", "a"), Path::new("a/b/"));
+            assert_eq!(resolve("a/b/", "a"), normalize("a/b/").as_os_str());
+        }
+
+        #[test]
+        fn handles_parent() {
+            assert_eq!(relative_to("a", "a/b"), Path::new("."));
+        }
+
+        #[test]
+        fn handles_parent_extension() {
+            assert_eq!(relative_to("a", "a/b.ext"), Path::new("."));
+        }
+
+        #[test]
+        fn handles_parent_folder() {
+            assert_eq!(relative_to("a", "a/b/"), Path::new(".."));
+        }
+
+        #[test]
+        fn handles_sibling() {
+            assert_eq!(relative_to("a/b", "a/c"), Path::new("b"));
+        }
+
+        #[test]
+        fn handles_sibling_folder() {
+            assert_eq!(relative_to("a/b", "a/c/"), Path::new("../b"));
+        }
+
+        #[test]
+        fn handles_dotdot() {
+            assert_eq!(relative_to(".", ".."), Path::new("."));
+        }
+
+        #[test]
+        fn handles_dotdot_nested() {
+            assert_eq!(relative_to("a", ".."), Path::new("a"));
         }
 
         #[test]
@@ -411,5 +924,423 @@ mod tests {
         fn handles_empty_base() {
             assert_eq!(relative_to("a", ""), Path::new("a"));
         }
+
+        #[test]
+        fn handles_folder_sibling_folder() {
+            assert_eq!(relative_to("a/b/", "c/"), Path::new("../a/b/"));
+            assert_eq!(resolve("a/b/", "c/"), normalize("a/b/").as_os_str());
+        }
+
+        #[test]
+        fn handles_folder_sibling() {
+            assert_eq!(relative_to("a/b/", "c"), Path::new("a/b/"));
+            assert_eq!(resolve("a/b/", "c"), normalize("a/b/").as_os_str());
+        }
+
+        #[test]
+        fn handles_sibling_folder_base() {
+            assert_eq!(relative_to("a/b", "c/"), Path::new("../a/b"));
+            assert_eq!(resolve("a/b", "c/"), normalize("a/b").as_os_str());
+        }
+
+        #[test]
+        fn handles_nested_folder_base() {
+            assert_eq!(relative_to("a/b", "a/"), Path::new("b"));
+            assert_eq!(resolve("a/b", "a/"), normalize("a/b").as_os_str());
+        }
+
+        /// Resolves the relative path from the given base path against the
+        /// folder of the base path, which is the base path itself if it ends
+        /// with a slash, so we can check that relative paths round-trip.
+        fn resolve(path: &str, base: &str) -> OsString {
+            let folder = if base.ends_with('/') {
+                Path::new(base)
+            } else {
+                Path::new(base).parent().unwrap_or(Path::new(""))
+            };
+            normalize(folder.join(relative_to(path, base))).into_os_string()
+        }
+    }
+
+    mod common_prefix {
+        use std::path::Path;
+
+        use crate::path::transform::common_prefix;
+
+        #[test]
+        fn handles_sibling() {
+            assert_eq!(common_prefix("a/b/c", "a/b/d"), Path::new("a/b"));
+        }
+
+        #[test]
+        fn handles_disjoint() {
+            assert_eq!(common_prefix("a/x", "b/y"), Path::new(""));
+        }
+
+        #[test]
+        fn handles_identical() {
+            assert_eq!(common_prefix("a/b/c", "a/b/c"), Path::new("a/b/c"));
+        }
+
+        #[test]
+        fn handles_nested() {
+            assert_eq!(common_prefix("a/b", "a/b/c"), Path::new("a/b"));
+        }
+
+        #[test]
+        fn handles_parent() {
+            assert_eq!(common_prefix("a/b/c", "a"), Path::new("a"));
+        }
+
+        #[test]
+        fn handles_folder() {
+            assert_eq!(common_prefix("a/b/", "a/b/c/"), Path::new("a/b"));
+        }
+
+        #[test]
+        fn handles_dotdot() {
+            assert_eq!(common_prefix("a/../b/c", "b/./d"), Path::new("b"));
+        }
+
+        #[test]
+        fn handles_empty() {
+            assert_eq!(common_prefix("", "a"), Path::new(""));
+        }
+
+        #[test]
+        fn handles_absolute() {
+            assert_eq!(common_prefix("/a/b", "/c"), Path::new("/"));
+        }
+    }
+
+    mod ancestors {
+        use std::path::PathBuf;
+
+        use crate::path::transform::ancestors;
+
+        #[test]
+        fn handles_nested() {
+            assert_eq!(
+                ancestors("a/b/c/d.md").collect::<Vec<_>>(),
+                vec![
+                    PathBuf::from("a/b/c/"),
+                    PathBuf::from("a/b/"),
+                    PathBuf::from("a/"),
+                    PathBuf::from(""),
+                ]
+            );
+        }
+
+        #[test]
+        fn handles_single() {
+            assert_eq!(
+                ancestors("a.md").collect::<Vec<_>>(),
+                vec![PathBuf::from("")]
+            );
+        }
+
+        #[test]
+        fn handles_folder() {
+            assert_eq!(
+                ancestors("a/b/").collect::<Vec<_>>(),
+                vec![PathBuf::from("a/"), PathBuf::from("")]
+            );
+        }
+
+        #[test]
+        fn handles_dot() {
+            assert_eq!(
+                ancestors("a/./b/../c.md").collect::<Vec<_>>(),
+                vec![PathBuf::from("a/"), PathBuf::from("")]
+            );
+        }
+
+        #[test]
+        fn handles_dotdot_leading() {
+            assert_eq!(
+                ancestors("../a/b.md").collect::<Vec<_>>(),
+                vec![PathBuf::from("../a/"), PathBuf::from("../")]
+            );
+        }
+
+        #[test]
+        fn handles_empty() {
+            assert_eq!(ancestors("").count(), 0);
+        }
+
+        #[test]
+        fn handles_absolute() {
+            assert_eq!(
+                ancestors("/a/b.md").collect::<Vec<_>>(),
+                vec![PathBuf::from("/a/"), PathBuf::from("/")]
+            );
+        }
+    }
+
+    mod strip_prefix_normalized {
+        use std::path::Path;
+
+        use crate::path::transform::strip_prefix_normalized;
+
+        #[test]
+        fn handles_dot() {
+            assert_eq!(
+                strip_prefix_normalized("a/./b/c", "a/b"),
+                Some(Path::new("c").to_path_buf())
+            );
+        }
+
+        #[test]
+        fn handles_dotdot() {
+            assert_eq!(
+                strip_prefix_normalized("a/b/c", "a/d/../b/"),
+                Some(Path::new("c").to_path_buf())
+            );
+        }
+
+        #[test]
+        fn handles_folder() {
+            assert_eq!(
+                strip_prefix_normalized("a/b/c/", "a"),
+                Some(Path::new("b/c/").to_path_buf())
+            );
+        }
+
+        #[test]
+        fn handles_mismatch() {
+            assert_eq!(strip_prefix_normalized("a/b/c", "a/c"), None);
+        }
+
+        #[test]
+        fn handles_mismatch_partial() {
+            assert_eq!(strip_prefix_normalized("a/bc", "a/b"), None);
+        }
+
+        #[test]
+        fn handles_mismatch_longer() {
+            assert_eq!(strip_prefix_normalized("a/b", "a/b/c"), None);
+        }
+
+        #[test]
+        fn handles_current() {
+            assert_eq!(
+                strip_prefix_normalized("a/b", "a/./b"),
+                Some(Path::new("").to_path_buf())
+            );
+        }
+
+        #[test]
+        fn handles_empty_prefix() {
+            assert_eq!(
+                strip_prefix_normalized("a/b", ""),
+                Some(Path::new("a/b").to_path_buf())
+            );
+        }
+    }
+
+    mod depth {
+        use crate::path::transform::depth;
+
+        #[test]
+        fn handles_file() {
+            assert_eq!(depth("a/b/c.md"), 2);
+        }
+
+        #[test]
+        fn handles_file_root() {
+            assert_eq!(depth("c.md"), 0);
+        }
+
+        #[test]
+        fn handles_folder() {
+            assert_eq!(depth("a/b/"), 2);
+        }
+
+        #[test]
+        fn handles_dot() {
+            assert_eq!(depth("a/./b/../c/d.md"), 2);
+        }
+
+        #[test]
+        fn handles_dotdot() {
+            assert_eq!(depth("../a/b/c.md"), 1);
+        }
+
+        #[test]
+        fn handles_dotdot_clamped() {
+            assert_eq!(depth("../../a/"), 0);
+        }
+
+        #[test]
+        fn handles_empty() {
+            assert_eq!(depth(""), 0);
+        }
+
+        #[test]
+        fn handles_root() {
+            assert_eq!(depth("/"), 0);
+        }
+
+        #[test]
+        fn handles_root_file() {
+            assert_eq!(depth("/a/b.md"), 1);
+        }
+    }
+
+    mod join_normalized {
+        use std::path::Path;
+
+        use crate::path::transform::{join_normalized, normalize};
+
+        #[test]
+        fn handles_nested() {
+            assert_eq!(join_normalized("a/b", "c"), Path::new("a/b/c"));
+        }
+
+        #[test]
+        fn handles_dotdot() {
+            assert_eq!(join_normalized("a/b/", "../c"), Path::new("a/c"));
+        }
+
+        #[test]
+        fn handles_dotdot_leading() {
+            assert_eq!(join_normalized("a", "../../c"), Path::new("../c"));
+        }
+
+        #[test]
+        fn handles_dot() {
+            assert_eq!(join_normalized("./a/./b", "./c"), Path::new("a/b/c"));
+        }
+
+        #[test]
+        fn handles_absolute() {
+            assert_eq!(join_normalized("a/b", "/c/../d"), Path::new("/d"));
+        }
+
+        #[test]
+        fn handles_trailing_slash() {
+            assert_eq!(join_normalized("a", "b/"), Path::new("a/b/"));
+        }
+
+        #[test]
+        fn handles_empty_base() {
+            assert_eq!(join_normalized("", "a/b"), Path::new("a/b"));
+        }
+
+        #[test]
+        fn handles_empty_segment() {
+            for base in ["a/b/", "a/b", ""] {
+                assert_eq!(
+                    join_normalized(base, ""),
+                    normalize(Path::new(base).join(""))
+                );
+            }
+            assert_eq!(join_normalized("a/b/", ""), Path::new("a/b/"));
+            assert_eq!(join_normalized("a/b", ""), Path::new("a/b/"));
+        }
+    }
+
+    mod ensure_trailing_slash {
+        use std::path::Path;
+
+        use crate::path::transform::ensure_trailing_slash;
+
+        #[test]
+        fn handles_file() {
+            assert_eq!(ensure_trailing_slash("a/b"), Path::new("a/b/"));
+        }
+
+        #[test]
+        fn handles_folder() {
+            assert_eq!(ensure_trailing_slash("a/b/"), Path::new("a/b/"));
+        }
+
+        #[test]
+        fn handles_idempotency() {
+            let path = ensure_trailing_slash("a/b");
+            assert_eq!(ensure_trailing_slash(&path), path);
+        }
+
+        #[test]
+        fn handles_redundant_slashes() {
+            assert_eq!(ensure_trailing_slash("a//b//"), Path::new("a/b/"));
+        }
+
+        #[test]
+        fn handles_dotdot() {
+            assert_eq!(ensure_trailing_slash(".."), Path::new("../"));
+        }
+
+        #[test]
+        fn handles_root() {
+            assert_eq!(ensure_trailing_slash("/"), Path::new("/"));
+        }
+
+        #[test]
+        fn handles_empty() {
+            assert_eq!(ensure_trailing_slash(""), Path::new(""));
+        }
+    }
+
+    mod strip_trailing_slash {
+        use std::path::Path;
+
+        use crate::path::transform::strip_trailing_slash;
+
+        #[test]
+        fn handles_folder() {
+            assert_eq!(strip_trailing_slash("a/b/"), Path::new("a/b"));
+        }
+
+        #[test]
+        fn handles_file() {
+            assert_eq!(strip_trailing_slash("a/b"), Path::new("a/b"));
+        }
+
+        #[test]
+        fn handles_idempotency() {
+            let path = strip_trailing_slash("a/b/");
+            assert_eq!(strip_trailing_slash(&path), path);
+        }
+
+        #[test]
+        fn handles_redundant_slashes() {
+            assert_eq!(strip_trailing_slash("a//b//"), Path::new("a/b"));
+        }
+
+        #[test]
+        fn handles_root() {
+            assert_eq!(strip_trailing_slash("/"), Path::new("/"));
+        }
+    }
+
+    mod to_uri_path {
+        use crate::path::transform::to_uri_path;
+
+        #[test]
+        fn handles_unix() {
+            assert_eq!(to_uri_path("a/./b/../c"), "a/c");
+        }
+
+        #[test]
+        fn handles_windows() {
+            assert_eq!(to_uri_path("a\\.\\b\\..\\c"), "a/c");
+        }
+
+        #[test]
+        fn handles_mixed() {
+            assert_eq!(to_uri_path("a\\b/c.md"), "a/b/c.md");
+        }
+
+        #[test]
+        fn handles_trailing_slash() {
+            assert_eq!(to_uri_path("a/b/"), "a/b/");
+            assert_eq!(to_uri_path("a\\b\\"), "a/b/");
+        }
+
+        #[test]
+        fn handles_dotdot_leading() {
+            assert_eq!(to_uri_path("..\\a"), "../a");
+        }
     }
 }
@@ -25,7 +25,7 @@
 
 //! Value.
 
-use std::any::Any;
+use std::any::{self, Any};
 use std::fmt::Debug;
 use std::time::{Duration, Instant};
 
@@ -36,13 +36,15 @@ use super::executor::graph::View;
 mod borrow;
 mod collection;
 mod convert;
+mod eq;
 mod error;
 mod ext;
 mod tuple;
 
 pub use borrow::IntoOwned;
-pub use collection::Values;
+pub use collection::{values_from, Values};
 pub use convert::{TryFromValue, TryFromValues};
+pub use eq::ValueEq;
 pub use error::{Error, Result};
 pub use ext::ValueExt;
 
@@ -68,7 +70,28 @@ pub use ext::ValueExt;
 /// values can be shared across thread boundaries and printed during debugging.
 ///
 /// [`Action`]: crate::scheduler::action::Action
-pub trait Value: Any + Debug + Send {}
+pub trait Value: Any + Debug + Send {
+    /// Returns the name of the concrete type of the value.
+    ///
+    /// This method allows to name the type behind a `dyn Value` in errors and
+    /// diagnostics, e.g., when a downcast fails. The name is obtained through
+    /// [`any::type_name`], so its exact format is not guaranteed to be stable
+    /// across compiler versions, and should only be used for diagnostics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_scheduler::Value;
+    ///
+    /// // Create value and obtain type name
+    /// let value: &dyn Value = &42;
+    /// assert_eq!(value.type_name(), "i32");
+    /// ```
+    #[inline]
+    fn type_name(&self) -> &'static str {
+        any::type_name::<Self>()
+    }
+}
 
 // ----------------------------------------------------------------------------
 // Implementations
@@ -103,6 +126,10 @@ impl dyn Value {
 
     /// Attempts to downcast the value to a reference of `T`.
     ///
+    /// This method mirrors [`Any`]'s `downcast_ref`, returning [`None`] if the
+    /// value is not of type `T`. Unlike [`TryFromValue`], no error is created,
+    /// which makes it a good fit for call sites that just probe for a type.
+    ///
     /// # Examples
     ///
     /// ```
@@ -114,6 +141,9 @@ impl dyn Value {
     ///     value.downcast_ref::<i32>(),
     ///     Some(&42),
     /// );
+    ///
+    /// // Downcast value to mismatched type
+    /// assert_eq!(value.downcast_ref::<u32>(), None);
     /// ```
     #[inline]
     pub fn downcast_ref<T>(&self) -> Option<&T>
@@ -128,7 +158,13 @@ impl dyn Value {
 // Trait implementations
 // ----------------------------------------------------------------------------
 
-impl Value for Box<dyn Value> {}
+impl Value for Box<dyn Value> {
+    /// Returns the name of the concrete type of the boxed value.
+    #[inline]
+    fn type_name(&self) -> &'static str {
+        self.as_ref().type_name()
+    }
+}
 
 impl<T> Value for Vec<T> where T: Value {}
 
@@ -158,3 +194,52 @@ impl_values!(Duration, Instant);
 // ----------------------------------------------------------------------------
 
 impl_values!(Id);
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod downcast_ref {
+        use crate::scheduler::value::Value;
+
+        #[test]
+        fn handles_match() {
+            let value: &dyn Value = &42;
+            assert_eq!(value.downcast_ref::<i32>(), Some(&42));
+        }
+
+        #[test]
+        fn handles_mismatch() {
+            let value: &dyn Value = &42;
+            assert_eq!(value.downcast_ref::<String>(), None);
+        }
+
+        #[test]
+        fn handles_boxed() {
+            let value: Box<dyn Value> = Box::new(String::from("value"));
+            assert_eq!(
+                value.as_ref().downcast_ref::<String>(),
+                Some(&String::from("value"))
+            );
+        }
+    }
+
+    mod type_name {
+        use crate::scheduler::value::Value;
+
+        #[test]
+        fn handles_value() {
+            let value: &dyn Value = &String::from("value");
+            assert_eq!(value.type_name(), "alloc::string::String");
+        }
+
+        #[test]
+        fn handles_boxed() {
+            let value: Box<dyn Value> = Box::new(42);
+            assert_eq!(Value::type_name(&value), "i32");
+        }
+    }
+}
@@ -231,3 +231,76 @@ impl<'a> FromIterator<Option<&'a dyn Value>> for Values<'a> {
         Values::Iter(Vec::from_iter(iter).into_iter())
     }
 }
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Creates a value collection from an iterator of value references.
+///
+/// This function is the runtime counterpart of the [`values!`][] macro, which
+/// is limited to a fixed number of expressions. It creates [`Values`] from an
+/// iterator over anything that can be referenced as a [`Value`], e.g., a
+/// slice of boxed values, wrapping each of them in [`Some`].
+///
+/// [`values!`]: crate::values!
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_scheduler::value::{values_from, Value};
+///
+/// // Create value collection from boxed values
+/// let values: Vec<Box<dyn Value>> = vec![Box::new(1), Box::new(2)];
+/// let target = values_from(&values).downcast::<Vec<&i32>>()?;
+/// assert_eq!(target, vec![&1, &2]);
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn values_from<'a, I, B>(iter: I) -> Values<'a>
+where
+    I: IntoIterator<Item = &'a B>,
+    B: AsRef<dyn Value> + ?Sized + 'a,
+{
+    iter.into_iter().map(|value| Some(value.as_ref())).collect()
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod values_from {
+        use crate::scheduler::value::{values_from, Result, Value};
+
+        #[test]
+        fn handles_boxed() -> Result {
+            let values: Vec<Box<dyn Value>> =
+                vec![Box::new(1), Box::new(2), Box::new(3)];
+            let target = values_from(&values).downcast::<Vec<&i32>>()?;
+            assert_eq!(target, vec![&1, &2, &3]);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_mixed() -> Result {
+            let values: Vec<Box<dyn Value>> =
+                vec![Box::new(1), Box::new("2"), Box::new(3)];
+            let target =
+                values_from(&values).downcast::<(&i32, &&str, &i32)>()?;
+            assert_eq!(target, (&1, &"2", &3));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_empty() {
+            let values: Vec<Box<dyn Value>> = Vec::new();
+            assert_eq!(values_from(&values).len(), 0);
+        }
+    }
+}
@@ -34,10 +34,12 @@
 /// This macro conveniently creates [`Values`][] from the provided expressions,
 /// each of which is expected to implement the [`Value`][] trait and which can
 /// then be passed to actions. Note that this is a low-level API, and in most
-/// cases, you will not need to use it directly.
+/// cases, you will not need to use it directly. For creating values from an
+/// iterator at runtime, use [`values_from`][] instead.
 ///
 /// [`Value`]: crate::scheduler::value::Value
 /// [`Values`]: crate::scheduler::value::Values
+/// [`values_from`]: crate::scheduler::value::values_from
 ///
 /// # Examples
 ///
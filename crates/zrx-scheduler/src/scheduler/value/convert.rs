@@ -141,7 +141,8 @@ where
     #[inline]
     fn try_from_value(opt: Option<&'a dyn Value>) -> Result<Self> {
         opt.map_or(Err(Error::Presence), |value| {
-            value.downcast_ref::<T>().ok_or(Error::Downcast)
+            let opt = value.downcast_ref::<T>();
+            opt.ok_or_else(|| Error::downcast::<T>(value))
         })
     }
 }
@@ -152,6 +153,10 @@ where
 {
     /// Attempts to convert into an optional reference of `T`.
     ///
+    /// Absent values are converted into [`None`], which allows actions to
+    /// accept genuinely optional inputs, while present values that can't be
+    /// downcast to `T` are still considered an error.
+    ///
     /// # Errors
     ///
     /// The following errors might occur:
@@ -169,13 +174,20 @@ where
     /// let opt = Some(&42 as &dyn Value);
     /// let target = <Option<&i32>>::try_from_value(opt)?;
     /// assert_eq!(target, Some(&42));
+    ///
+    /// // Convert absent value
+    /// let target = <Option<&i32>>::try_from_value(None)?;
+    /// assert_eq!(target, None);
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
     fn try_from_value(opt: Option<&'a dyn Value>) -> Result<Self> {
         opt.map_or(Ok(None), |value| {
-            value.downcast_ref::<T>().ok_or(Error::Downcast).map(Some)
+            value
+                .downcast_ref::<T>()
+                .ok_or_else(|| Error::downcast::<T>(value))
+                .map(Some)
         })
     }
 }
@@ -237,10 +249,11 @@ where
         let mut iter = values.into_iter();
 
         // Ensure that the iterator yields exactly one value
-        if let (Some(opt), None) = (iter.next(), iter.next()) {
-            T::try_from_value(opt)
-        } else {
-            Err(Error::Mismatch)
+        let opt = iter.next();
+        let actual = usize::from(opt.is_some()) + iter.count();
+        match opt {
+            Some(opt) if actual == 1 => T::try_from_value(opt),
+            _ => Err(Error::Mismatch { expected: 1, actual }),
         }
     }
 }
@@ -296,7 +309,11 @@ where
     where
         V: IntoIterator<Item = Option<&'a dyn Value>>,
     {
-        values.into_iter().map(T::try_from_value).collect()
+        let iter = values.into_iter().enumerate();
+        iter.map(|(index, opt)| {
+            T::try_from_value(opt).map_err(|err| err.with_index(index))
+        })
+        .collect()
     }
 }
 
@@ -329,9 +346,9 @@ impl<'a> TryFromValues<'a> for () {
     where
         V: IntoIterator<Item = Option<&'a dyn Value>>,
     {
-        match values.into_iter().next() {
-            Some(_) => Err(Error::Mismatch),
-            None => Ok(()),
+        match values.into_iter().count() {
+            0 => Ok(()),
+            actual => Err(Error::Mismatch { expected: 0, actual }),
         }
     }
 }
@@ -352,19 +369,31 @@ macro_rules! impl_try_from_values_for_tuple {
             where
                 V: IntoIterator<Item = Option<&'a dyn Value>>,
             {
-                let mut iter = values.into_iter();
+                let mut iter = values.into_iter().enumerate();
                 $(
                     #[allow(non_snake_case)]
-                    let $T = $T::try_from_value(iter.next()
-                        .ok_or(Error::Mismatch)?)?;
+                    let $T = iter.next();
                 )+
 
-                // Ensure that the iterator yields no more values
-                if iter.next().is_none() {
-                    Ok(($($T,)+))
-                } else {
-                    Err(Error::Mismatch)
+                // Ensure that the iterator yields exactly as many values as
+                // the tuple has elements, counting all values in case it's
+                // off, so the error carries the number of values supplied
+                let expected = [$(stringify!($T)),+].len();
+                let actual = [$($T.is_some()),+]
+                    .into_iter()
+                    .filter(|&present| present)
+                    .count() + iter.count();
+                if actual != expected {
+                    return Err(Error::Mismatch { expected, actual });
                 }
+
+                // Convert each value into the respective element type, and
+                // attach the index of the value in case conversion fails
+                Ok(($({
+                    let (index, opt) = $T.unwrap_or_default();
+                    $T::try_from_value(opt)
+                        .map_err(|err| err.with_index(index))?
+                },)+))
             }
         }
     };
@@ -380,3 +409,123 @@ impl_try_from_values_for_tuple!(T1, T2, T3, T4, T5);
 impl_try_from_values_for_tuple!(T1, T2, T3, T4, T5, T6);
 impl_try_from_values_for_tuple!(T1, T2, T3, T4, T5, T6, T7);
 impl_try_from_values_for_tuple!(T1, T2, T3, T4, T5, T6, T7, T8);
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod try_from_value {
+        use crate::scheduler::value::{Error, TryFromValue, Value};
+
+        #[test]
+        fn handles_downcast() {
+            let opt = Some(&"x" as &dyn Value);
+            let err = <&i32>::try_from_value(opt);
+            assert!(matches!(
+                err,
+                Err(Error::Downcast { index: None, expected: "i32", .. })
+            ));
+            assert_eq!(
+                err.unwrap_err().to_string(),
+                "value could not be downcast: expected i32, found &str"
+            );
+        }
+    }
+
+    mod try_from_values {
+        use crate::scheduler::value::{Error, Result, TryFromValues, Value};
+        use crate::values;
+
+        #[test]
+        fn handles_optional() -> Result {
+            let values = [Some(&1 as &dyn Value), None, Some(&3 as &dyn Value)];
+            let target = <(&i32, Option<&i32>, &i32)>::try_from_values(values)?;
+            assert_eq!(target, (&1, None, &3));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_optional_downcast() {
+            let values = [Some(&1 as &dyn Value), Some(&"2" as &dyn Value)];
+            assert!(matches!(
+                <(&i32, Option<&i32>)>::try_from_values(values),
+                Err(Error::Downcast { index: Some(1), .. })
+            ));
+        }
+
+        #[test]
+        fn handles_downcast_index() {
+            let values = values!(&1, &"x", &3);
+            let err = <(&i32, &i32, &i32)>::try_from_values(values);
+            assert!(matches!(
+                err,
+                Err(Error::Downcast {
+                    index: Some(1),
+                    expected: "i32",
+                    actual: "&str"
+                })
+            ));
+            assert_eq!(
+                err.unwrap_err().to_string(),
+                "value at index 1 could not be downcast: expected i32, found &str"
+            );
+        }
+
+        #[test]
+        fn handles_downcast_index_vec() {
+            let values = values!(&1, &2, &"x");
+            assert!(matches!(
+                <Vec<&i32>>::try_from_values(values),
+                Err(Error::Downcast { index: Some(2), .. })
+            ));
+        }
+
+        #[test]
+        fn handles_mismatch() {
+            let values = values!(&1, &2, &3);
+            assert!(matches!(
+                <(&i32, &i32)>::try_from_values(values),
+                Err(Error::Mismatch { expected: 2, actual: 3 })
+            ));
+        }
+
+        #[test]
+        fn handles_mismatch_fewer() {
+            let values = values!(&1);
+            assert!(matches!(
+                <(&i32, &i32, &i32)>::try_from_values(values),
+                Err(Error::Mismatch { expected: 3, actual: 1 })
+            ));
+        }
+
+        #[test]
+        fn handles_mismatch_single() {
+            let values = values!(&1, &2);
+            assert!(matches!(
+                <&i32>::try_from_values(values),
+                Err(Error::Mismatch { expected: 1, actual: 2 })
+            ));
+        }
+
+        #[test]
+        fn handles_mismatch_unit() {
+            let values = values!(&1);
+            assert!(matches!(
+                <()>::try_from_values(values),
+                Err(Error::Mismatch { expected: 0, actual: 1 })
+            ));
+        }
+
+        #[test]
+        fn handles_presence() {
+            let values = [Some(&1 as &dyn Value), None];
+            assert!(matches!(
+                <(&i32, &i32)>::try_from_values(values),
+                Err(Error::Presence)
+            ));
+        }
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright (c) 2025-2026 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// All contributions are certified under the DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Value equality.
+
+use std::any::Any;
+
+use super::Value;
+
+// ----------------------------------------------------------------------------
+// Traits
+// ----------------------------------------------------------------------------
+
+/// Value equality.
+///
+/// This trait allows to compare a value with a type-erased [`Value`] without
+/// knowing its concrete type, which is useful for change detection, e.g., when
+/// caching outputs of actions keyed by their inputs. Values of different types
+/// are never considered to be equal.
+pub trait ValueEq {
+    /// Returns whether the value is equal to the given type-erased value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_scheduler::value::ValueEq;
+    /// use zrx_scheduler::Value;
+    ///
+    /// // Compare value with type-erased values
+    /// let value: &dyn Value = &42;
+    /// assert!(42.value_eq(value));
+    /// assert!(!42u32.value_eq(value));
+    /// ```
+    fn value_eq(&self, other: &dyn Value) -> bool;
+}
+
+// ----------------------------------------------------------------------------
+// Blanket implementations
+// ----------------------------------------------------------------------------
+
+impl<T> ValueEq for T
+where
+    T: Any + PartialEq,
+{
+    #[inline]
+    fn value_eq(&self, other: &dyn Value) -> bool {
+        other.downcast_ref::<T>().is_some_and(|other| self == other)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod value_eq {
+        use crate::scheduler::value::{Value, ValueEq};
+
+        #[test]
+        fn handles_equal() {
+            let value: &dyn Value = &42;
+            assert!(42.value_eq(value));
+        }
+
+        #[test]
+        fn handles_unequal() {
+            let value: &dyn Value = &42;
+            assert!(!84.value_eq(value));
+        }
+
+        #[test]
+        fn handles_different_types() {
+            let value: &dyn Value = &42;
+            assert!(!String::from("42").value_eq(value));
+            assert!(!42i64.value_eq(value));
+        }
+    }
+}
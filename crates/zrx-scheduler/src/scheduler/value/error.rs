@@ -25,9 +25,11 @@
 
 //! Value error.
 
-use std::result;
+use std::{any, result};
 use thiserror::Error;
 
+use super::Value;
+
 // ----------------------------------------------------------------------------
 // Enums
 // ----------------------------------------------------------------------------
@@ -36,16 +38,60 @@ use thiserror::Error;
 #[derive(Debug, Error)]
 pub enum Error {
     /// Value number mismatch.
-    #[error("value number mismatch")]
-    Mismatch,
+    #[error("value number mismatch: expected {expected}, got {actual}")]
+    Mismatch {
+        /// Expected number of values.
+        expected: usize,
+        /// Actual number of values.
+        actual: usize,
+    },
 
     /// Value is not present.
     #[error("value is not present")]
     Presence,
 
     /// Value downcast failed.
-    #[error("value downcast failed")]
-    Downcast,
+    #[error(
+        "value{} could not be downcast: expected {expected}, found {actual}",
+        index.map(|index| format!(" at index {index}")).unwrap_or_default()
+    )]
+    Downcast {
+        /// Index of value, if converted as part of multiple values.
+        index: Option<usize>,
+        /// Name of expected type.
+        expected: &'static str,
+        /// Name of actual type.
+        actual: &'static str,
+    },
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Error {
+    /// Creates a downcast error for the given target type and value.
+    ///
+    /// The index is initially unset, and must be set by conversions of multiple
+    /// values with [`Error::with_index`], as single conversions can't know the
+    /// position of the value they're converting.
+    pub(super) fn downcast<T>(value: &dyn Value) -> Self {
+        Error::Downcast {
+            index: None,
+            expected: any::type_name::<T>(),
+            actual: value.type_name(),
+        }
+    }
+
+    /// Sets the index of the value that caused the error, if applicable.
+    pub(super) fn with_index(self, index: usize) -> Self {
+        match self {
+            Error::Downcast { expected, actual, .. } => {
+                Error::Downcast { index: Some(index), expected, actual }
+            }
+            err => err,
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -35,6 +35,7 @@ pub use store::decorator;
 pub use store::key::Key;
 pub use store::{
     Store, StoreFromIterator, StoreIntoIterator, StoreIterable,
-    StoreIterableMut, StoreKeys, StoreMut, StoreMutRef, StoreRange,
+    StoreIterableMut, StoreKeyValue, StoreKeys, StoreMut, StoreMutRef,
+    StoreRange, StoreSeek, StoreSnapshot, StoreStats, StoreTransform,
     StoreValues, StoreWithComparator,
 };
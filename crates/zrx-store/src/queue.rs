@@ -25,15 +25,19 @@
 
 //! Queue.
 
-use ahash::HashMap;
+use ahash::{HashMap, HashSet};
 use slab::Slab;
 use std::borrow::Borrow;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use std::{fmt, mem};
 
+use crate::store::behavior::{Checkpoint, StoreRestore};
 use crate::store::decorator::Ordered;
 use crate::store::key::Key;
-use crate::store::{Store, StoreIterable, StoreMut, StoreMutRef};
+use crate::store::{
+    Store, StoreIterable, StoreKeyValue, StoreMut, StoreMutRef, StoreStats,
+    StoreTransform,
+};
 
 mod item;
 mod iter;
@@ -146,6 +150,113 @@ where
     pub fn get_deadline(&self, key: &K) -> Option<Instant> {
         self.store.get(key).map(Item::deadline)
     }
+
+    /// Creates an iterator over all items of a queue, regardless of deadline.
+    ///
+    /// In contrast to [`Queue::iter`][], which only yields items that are due,
+    /// this method also yields items whose deadline lies in the future, which
+    /// is useful for inspecting the entire contents of the queue.
+    ///
+    /// [`Queue::iter`]: crate::store::StoreIterable::iter
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use zrx_store::queue::Queue;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create queue and initial state
+    /// let mut queue = Queue::default();
+    /// queue.insert("key", 42);
+    ///
+    /// // Defer item
+    /// let deadline = Instant::now() + Duration::from_secs(60);
+    /// queue.set_deadline(&"key", deadline);
+    ///
+    /// // Create iterator over the queue
+    /// for (key, value) in queue.iter_all() {
+    ///     println!("{key}: {value}");
+    /// }
+    /// ```
+    #[inline]
+    pub fn iter_all(&self) -> impl Iterator<Item = (&K, &V)> {
+        let iter = self.store.iter();
+        iter.map(|(key, item)| (key, &self.items[*item.data()]))
+    }
+
+    /// Returns the number of all items in the queue, regardless of deadline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::queue::Queue;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create queue and initial state
+    /// let mut queue = Queue::default();
+    /// queue.insert("key", 42);
+    ///
+    /// // Obtain number of all items
+    /// assert_eq!(queue.len_all(), 1);
+    /// ```
+    #[inline]
+    pub fn len_all(&self) -> usize {
+        self.store.len()
+    }
+
+    /// Checks whether the queue is consistent with its items.
+    ///
+    /// This method verifies the invariants of the underlying [`Ordered`]
+    /// decorator, and that every item refers to a distinct slot in the slab,
+    /// which holds no other values. It's intended for testing, e.g., in
+    /// property tests, and has a complexity of O(n).
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error describing the first violated invariant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::queue::Queue;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create queue and initial state
+    /// let mut queue = Queue::default();
+    /// queue.insert("a", 42);
+    /// queue.insert("b", 84);
+    ///
+    /// // Check invariants
+    /// assert!(queue.check_invariants().is_ok());
+    /// ```
+    pub fn check_invariants(&self) -> Result<(), String> {
+        self.store.check_invariants()?;
+
+        // Ensure all items refer to distinct, occupied slots in the slab
+        let mut seen = HashSet::default();
+        for (n, (_, item)) in self.store.iter().enumerate() {
+            let index = *item.data();
+            if !self.items.contains(index) {
+                return Err(format!("item at index {n} has no value"));
+            }
+            if !seen.insert(index) {
+                return Err(format!("item at index {n} shares its value"));
+            }
+        }
+
+        // Ensure the slab holds no values without a corresponding item
+        if self.items.len() != self.store.len() {
+            return Err(format!(
+                "slab has {} values, but store has {} items",
+                self.items.len(),
+                self.store.len()
+            ));
+        }
+
+        // All invariants hold
+        Ok(())
+    }
 }
 
 impl<K, V, S> Queue<K, V, S>
@@ -180,6 +291,44 @@ where
                 .map(|prior| prior.deadline())
         })
     }
+
+    /// Inserts the value identified by the key with the given deadline.
+    ///
+    /// In contrast to [`Queue::insert`][], which annotates new items with
+    /// [`Instant::now`], this method creates the [`Item`] with the given
+    /// deadline, so the ordering is only updated once. If the item already
+    /// exists, its value is replaced and its deadline is updated.
+    ///
+    /// [`Queue::insert`]: crate::store::StoreMut::insert
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use zrx_store::queue::Queue;
+    ///
+    /// // Create queue and insert value with deadline
+    /// let mut queue = Queue::default();
+    /// let deadline = Instant::now() + Duration::from_secs(60);
+    /// queue.insert_with_deadline("key", 42, deadline);
+    ///
+    /// // Item is not due yet
+    /// assert_eq!(queue.take(), None);
+    /// ```
+    #[inline]
+    pub fn insert_with_deadline(
+        &mut self, key: K, value: V, deadline: Instant,
+    ) -> Option<V> {
+        if let Some(item) = self.store.get(&key) {
+            let n = *item.data();
+            self.set_deadline(&key, deadline);
+            Some(mem::replace(&mut self.items[n], value))
+        } else {
+            let n = self.items.insert(value);
+            self.store.insert(key, Item::with_deadline(n, deadline));
+            None
+        }
+    }
 }
 
 impl<K, V, S> Queue<K, V, S>
@@ -209,6 +358,85 @@ where
         self.store.iter().next().map(|(_, item)| item.deadline())
     }
 
+    /// Returns the duration until the minimum deadline of all items.
+    ///
+    /// If an item is already due, [`Duration::ZERO`] is returned, so the
+    /// result can be directly used as a timeout for waiting on the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use zrx_store::queue::Queue;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create queue and initial state
+    /// let mut queue = Queue::default();
+    /// queue.insert("key", 42);
+    ///
+    /// // Obtain duration until next item is due
+    /// let duration = queue.time_until_next();
+    /// assert_eq!(duration, Some(Duration::ZERO));
+    /// ```
+    #[inline]
+    pub fn time_until_next(&self) -> Option<Duration> {
+        self.deadline()
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
+    /// Returns whether any item is currently due.
+    ///
+    /// Only the item with the minimum deadline needs to be checked, so this is
+    /// a cheap way to determine whether [`Queue::take`] would yield an item,
+    /// e.g., for returning early when the queue only holds future items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::queue::Queue;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create queue and initial state
+    /// let mut queue = Queue::default();
+    /// queue.insert("key", 42);
+    ///
+    /// // Ensure presence of due items
+    /// assert!(queue.has_due());
+    /// ```
+    #[inline]
+    pub fn has_due(&self) -> bool {
+        self.deadline()
+            .is_some_and(|deadline| deadline <= Instant::now())
+    }
+
+    /// Returns the key of the next item that is due.
+    ///
+    /// This is a cheap way to peek at the item [`Queue::take`] yields next,
+    /// e.g., to look up associated data elsewhere, without removing the item
+    /// or borrowing its value, as only the minimum deadline is checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::queue::Queue;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create queue and initial state
+    /// let mut queue = Queue::default();
+    /// queue.insert("key", 42);
+    ///
+    /// // Obtain key of next due item
+    /// let key = queue.next_key();
+    /// assert_eq!(key, Some(&"key"));
+    /// ```
+    #[inline]
+    pub fn next_key(&self) -> Option<&K> {
+        let deadline = Instant::now();
+        self.store.iter().next().and_then(|(key, item)| {
+            (item.deadline() <= deadline).then_some(key)
+        })
+    }
+
     /// Takes ownership of the next item that is due.
     ///
     /// Items are considered to be due if [`Instant::now`] has passed the value
@@ -252,6 +480,151 @@ where
                 .expect("invariant")
         })
     }
+
+    /// Drains all items that are due into the given buffer.
+    ///
+    /// Items are appended to the buffer in queue order, until the first item
+    /// that is not yet due is encountered. The buffer is not cleared, so it
+    /// can be reused across calls in order to avoid repeated allocations.
+    /// Returns the number of items appended to the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::queue::Queue;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create queue and initial state
+    /// let mut queue = Queue::default();
+    /// queue.insert("a", 4);
+    /// queue.insert("b", 2);
+    /// queue.insert("c", 3);
+    /// queue.insert("d", 1);
+    ///
+    /// // Drain due items from queue into buffer
+    /// let mut buf = Vec::new();
+    /// let count = queue.drain_due_into(&mut buf);
+    /// assert_eq!(count, 4);
+    /// ```
+    #[allow(clippy::missing_panics_doc)]
+    pub fn drain_due_into(&mut self, buf: &mut Vec<(K, V)>) -> usize {
+        // Obtain the current instant once, so that all items are compared
+        // against the same point in time, and the loop is guaranteed to end
+        let deadline = Instant::now();
+        let len = buf.len();
+        loop {
+            let opt = self.store.iter().next().and_then(|(key, item)| {
+                (item.deadline() <= deadline).then(|| key.clone())
+            });
+
+            // Stop at the first item that is not yet due, as items are ordered
+            let Some(key) = opt else {
+                break;
+            };
+
+            // We can safely use expect here, since we're iterating over a
+            // store that is synchronized with the ordering
+            let value = self.remove(&key).expect("invariant");
+            buf.push((key, value));
+        }
+
+        // Return number of appended items
+        buf.len() - len
+    }
+
+    /// Retains only the due items for which the predicate returns `true`.
+    ///
+    /// All items that are due are visited in queue order, and removed if the
+    /// predicate returns `false`, which allows to process items and keep some
+    /// of them in one pass, e.g., for retrying them later. Items that are not
+    /// yet due are never visited. Kept items retain their deadline, which can
+    /// be changed afterwards with [`Queue::set_deadline`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::queue::Queue;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create queue and initial state
+    /// let mut queue = Queue::default();
+    /// queue.insert("a", 1);
+    /// queue.insert("b", 2);
+    ///
+    /// // Retain due items with odd values
+    /// queue.retain_due(|_, value| *value % 2 != 0);
+    /// assert_eq!(queue.len(), 1);
+    /// ```
+    #[allow(clippy::missing_panics_doc)]
+    pub fn retain_due<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        // Obtain the current instant once, so that all items are compared
+        // against the same point in time, and collect the due keys upfront,
+        // as the predicate might decide to remove them during iteration
+        let deadline = Instant::now();
+        let keys: Vec<_> = self
+            .store
+            .iter()
+            .take_while(|(_, item)| item.deadline() <= deadline)
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        // Invoke the predicate for each due item, and remove it if rejected
+        for key in keys {
+            // We can safely use expect here, since we're iterating over keys
+            // that are synchronized with the underlying store
+            let value = self.get_mut(&key).expect("invariant");
+            if !f(&key, value) {
+                self.remove(&key);
+            }
+        }
+    }
+
+    /// Removes all items matching the given predicate.
+    ///
+    /// In contrast to [`Queue::retain_due`], this method considers all items,
+    /// regardless of whether they're due, which allows to cancel deferred items
+    /// matching some criterion, e.g., all items for a deleted resource. Returns
+    /// the number of removed items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::queue::Queue;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create queue and initial state
+    /// let mut queue = Queue::default();
+    /// queue.insert("a", 4);
+    /// queue.insert("b", 2);
+    /// queue.insert("c", 3);
+    /// queue.insert("d", 1);
+    ///
+    /// // Remove items with even values
+    /// let count = queue.remove_where(|_, value| value % 2 == 0);
+    /// assert_eq!(count, 2);
+    /// ```
+    pub fn remove_where<F>(&mut self, mut f: F) -> usize
+    where
+        F: FnMut(&K, &V) -> bool,
+    {
+        // Collect the matching keys upfront, as we can't remove items from the
+        // underlying store while iterating over it
+        let keys: Vec<_> = self
+            .store
+            .iter()
+            .filter(|(key, item)| f(key, &self.items[*item.data()]))
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        // Remove all matching items, which releases their values in the slab
+        for key in &keys {
+            self.remove(key);
+        }
+        keys.len()
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -323,6 +696,42 @@ where
     }
 }
 
+impl<K, V, S> StoreKeyValue<K, V> for Queue<K, V, S>
+where
+    K: Key,
+    S: StoreKeyValue<K, Item>,
+{
+    /// Returns references to the key and value identified by the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::queue::Queue;
+    /// use zrx_store::{StoreKeyValue, StoreMut};
+    ///
+    /// // Create queue and initial state
+    /// let mut queue = Queue::default();
+    /// queue.insert("key", 42);
+    ///
+    /// // Obtain references to key and value
+    /// let entry = queue.get_key_value(&"key");
+    /// assert_eq!(entry, Some((&"key", &42)));
+    /// ```
+    #[inline]
+    fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        match self.store.get_key_value(key) {
+            Some((key, item)) => {
+                self.items.get(*item.data()).map(|value| (key, value))
+            }
+            None => None,
+        }
+    }
+}
+
 impl<K, V, S> StoreMut<K, V> for Queue<K, V, S>
 where
     K: Key,
@@ -436,6 +845,54 @@ where
         self.store.clear();
         self.items.clear();
     }
+
+    /// Shrinks the capacity of the queue as much as possible.
+    ///
+    /// This method compacts the slab holding the values of all items, which
+    /// moves values to lower indices, so all affected items are updated to
+    /// point to the new indices, retaining their deadlines and queue order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::queue::Queue;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create queue and initial state
+    /// let mut queue = Queue::default();
+    /// queue.insert("a", 4);
+    /// queue.insert("b", 2);
+    /// queue.remove(&"a");
+    ///
+    /// // Shrink queue
+    /// queue.shrink_to_fit();
+    /// assert_eq!(queue.get(&"b"), Some(&2));
+    /// ```
+    fn shrink_to_fit(&mut self) {
+        let mut moves = HashMap::default();
+        self.items.compact(|_, from, to| {
+            moves.insert(from, to);
+            true
+        });
+
+        // If values were moved during compaction, we reinsert all items in
+        // queue order, updating the indices of moved values, since items with
+        // equal deadlines must retain their relative order, which holds, as
+        // sequence numbers are renewed in queue order. Note that we need to
+        // collect all items first, as we can't update while iterating.
+        if !moves.is_empty() {
+            let iter = self.store.iter().map(|(key, item)| {
+                let n = moves.get(item.data()).unwrap_or(item.data());
+                (key.clone(), Item::with_deadline(*n, item.deadline()))
+            });
+            for (key, item) in iter.collect::<Vec<_>>() {
+                self.store.insert(key, item);
+            }
+        }
+
+        // Shrink underlying store
+        self.store.shrink_to_fit();
+    }
 }
 
 impl<K, V, S> StoreMutRef<K, V> for Queue<K, V, S>
@@ -499,6 +956,171 @@ where
         // We can safely use expect here, as the key is present
         self.get_mut(key).expect("invariant")
     }
+
+    /// Returns a mutable reference to the value or creates it with the given
+    /// function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::queue::Queue;
+    /// use zrx_store::StoreMutRef;
+    ///
+    /// // Create queue
+    /// let mut queue = Queue::default();
+    ///
+    /// // Obtain mutable reference to value
+    /// let value = queue.get_or_insert_with(&"key", || 42);
+    /// assert_eq!(value, &mut 42);
+    /// ```
+    #[inline]
+    fn get_or_insert_with<F>(&mut self, key: &K, f: F) -> &mut V
+    where
+        F: FnOnce() -> V,
+    {
+        if !self.store.contains_key(key) {
+            let n = self.items.insert(f());
+            self.store.insert(key.clone(), Item::new(n));
+        }
+
+        // We can safely use expect here, as the key is present
+        self.get_mut(key).expect("invariant")
+    }
+}
+
+impl<K, V, S> StoreStats<K, V> for Queue<K, V, S>
+where
+    K: Key,
+    S: StoreStats<K, Item>,
+{
+    /// Returns the number of items the queue can hold without reallocating.
+    ///
+    /// This method returns the smaller of the capacities of the underlying
+    /// store and the slab holding the values of all items, as both must grow
+    /// when items are inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::queue::Queue;
+    /// use zrx_store::{StoreMut, StoreStats};
+    ///
+    /// // Create queue and initial state
+    /// let mut queue = Queue::default();
+    /// queue.insert("key", 42);
+    ///
+    /// // Obtain capacity of queue
+    /// assert!(queue.capacity() >= 1);
+    /// ```
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.store.capacity().min(self.items.capacity())
+    }
+}
+
+impl<K, V, S> StoreRestore<K, V> for Queue<K, V, S>
+where
+    K: Key,
+    S: StoreMut<K, Item> + StoreIterable<K, Item>,
+{
+    type Checkpoint = Checkpoint<K, (V, Instant)>;
+
+    /// Creates a checkpoint of all items of the queue, including deadlines.
+    ///
+    /// In contrast to iteration, this method captures all items regardless of
+    /// their deadlines, so items that are not due yet are retained.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::behavior::StoreRestore;
+    /// use zrx_store::queue::Queue;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create queue and initial state
+    /// let mut queue = Queue::default();
+    /// queue.insert("key", 42);
+    ///
+    /// // Create checkpoint of queue
+    /// let checkpoint = queue.snapshot();
+    /// ```
+    #[inline]
+    fn snapshot(&self) -> Self::Checkpoint
+    where
+        V: Clone,
+    {
+        let iter = self.store.iter().map(|(key, item)| {
+            let value = self.items[*item.data()].clone();
+            (key.clone(), (value, item.deadline()))
+        });
+        Checkpoint::from(iter.collect::<Vec<_>>())
+    }
+
+    /// Restores the queue to the given checkpoint, including deadlines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use zrx_store::behavior::StoreRestore;
+    /// use zrx_store::queue::Queue;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create queue and initial state
+    /// let mut queue = Queue::default();
+    /// let deadline = Instant::now() + Duration::from_secs(60);
+    /// queue.insert_with_deadline("key", 42, deadline);
+    ///
+    /// // Create checkpoint and mutate queue
+    /// let checkpoint = queue.snapshot();
+    /// queue.clear();
+    ///
+    /// // Restore queue to checkpoint
+    /// queue.restore(checkpoint);
+    /// assert_eq!(queue.get_deadline(&"key"), Some(deadline));
+    /// ```
+    #[inline]
+    fn restore(&mut self, checkpoint: Self::Checkpoint) {
+        self.clear();
+        for (key, (value, deadline)) in checkpoint {
+            self.insert_with_deadline(key, value, deadline);
+        }
+    }
+}
+
+impl<K, V, S> StoreTransform<K, V> for Queue<K, V, S>
+where
+    K: Key,
+    S: StoreMut<K, Item> + StoreIterable<K, Item>,
+{
+    /// Applies the given function to all items of the queue.
+    ///
+    /// In contrast to iteration, this method applies the function to all items
+    /// regardless of their deadlines, as it doesn't change the queue order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::queue::Queue;
+    /// use zrx_store::{Store, StoreMut, StoreTransform};
+    ///
+    /// // Create queue and initial state
+    /// let mut queue = Queue::default();
+    /// queue.insert("key", 42);
+    ///
+    /// // Transform all values in place
+    /// queue.transform(|_, value| *value *= 2);
+    /// assert_eq!(queue.get(&"key"), Some(&84));
+    /// ```
+    #[inline]
+    fn transform<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V),
+    {
+        for (key, item) in self.store.iter() {
+            f(key, &mut self.items[*item.data()]);
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -550,3 +1172,473 @@ where
             .finish()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod iter_all {
+        use std::time::{Duration, Instant};
+
+        use crate::queue::Queue;
+        use crate::{StoreIterable, StoreMut};
+
+        #[test]
+        fn handles_deadline() {
+            let mut queue = Queue::default();
+            queue.insert("a", 1);
+            queue.insert("b", 2);
+            let deadline = Instant::now() + Duration::from_secs(60);
+            queue.set_deadline(&"b", deadline);
+            assert_eq!(
+                queue.iter().collect::<Vec<_>>(), // fmt
+                vec![(&"a", &1)]
+            );
+            assert_eq!(
+                queue.iter_all().collect::<Vec<_>>(),
+                vec![(&"a", &1), (&"b", &2)]
+            );
+            assert_eq!(queue.len_all(), 2);
+        }
+    }
+
+    mod restore {
+        use std::time::{Duration, Instant};
+
+        use crate::behavior::StoreRestore;
+        use crate::queue::Queue;
+        use crate::StoreMut;
+
+        #[test]
+        fn handles_deadline() {
+            let mut queue = Queue::default();
+            queue.insert("a", 1);
+            let deadline = Instant::now() + Duration::from_secs(60);
+            queue.insert_with_deadline("b", 2, deadline);
+            let checkpoint = queue.snapshot();
+            assert_eq!(checkpoint.len(), 2);
+            queue.insert("c", 3);
+            StoreMut::remove(&mut queue, &"b");
+            queue.restore(checkpoint);
+            assert_eq!(
+                queue.iter_all().collect::<Vec<_>>(),
+                vec![(&"a", &1), (&"b", &2)]
+            );
+            assert_eq!(queue.get_deadline(&"b"), Some(deadline));
+            assert_eq!(queue.take(), Some(("a", 1)));
+            assert_eq!(queue.take(), None);
+            assert_eq!(queue.check_invariants(), Ok(()));
+        }
+    }
+
+    mod check_invariants {
+        use std::time::{Duration, Instant};
+
+        use crate::queue::Queue;
+        use crate::StoreMut;
+
+        #[test]
+        fn handles_valid() {
+            let mut queue = Queue::default();
+            queue.insert("a", 1);
+            queue.insert("b", 2);
+            queue.insert("c", 3);
+            let deadline = Instant::now() + Duration::from_secs(60);
+            queue.set_deadline(&"b", deadline);
+            queue.remove(&"a");
+            assert_eq!(queue.check_invariants(), Ok(()));
+        }
+
+        #[test]
+        fn handles_invalid() {
+            let mut queue = Queue::default();
+            queue.insert("a", 1);
+            queue.items.insert(2);
+            assert!(queue.check_invariants().is_err());
+        }
+    }
+
+    mod capacity {
+        use crate::queue::Queue;
+        use crate::{StoreMut, StoreStats};
+
+        #[test]
+        fn handles_insert() {
+            let mut queue = Queue::default();
+            for n in 0..100 {
+                queue.insert(n, n * 2);
+            }
+            assert!(queue.capacity() >= 100);
+        }
+
+        #[test]
+        fn handles_removal() {
+            let mut queue = Queue::default();
+            for n in 0..100 {
+                queue.insert(n, n * 2);
+            }
+            for n in 0..90 {
+                queue.remove(&n);
+            }
+            assert_eq!(queue.len_all(), 10);
+            assert!(queue.capacity() > queue.len_all());
+        }
+    }
+
+    mod shrink_to_fit {
+        use crate::queue::Queue;
+        use crate::{Store, StoreMut, StoreStats};
+
+        #[test]
+        fn handles_removal() {
+            let mut queue = Queue::default();
+            for n in 0..1000 {
+                queue.insert(n, n * 2);
+            }
+            for n in 0..900 {
+                queue.remove(&n);
+            }
+            let capacity = queue.capacity();
+            queue.shrink_to_fit();
+            assert!(queue.capacity() < capacity);
+            assert_eq!(queue.len_all(), 100);
+            for n in 900..1000 {
+                assert_eq!(queue.get(&n), Some(&(n * 2)));
+            }
+            for n in 900..1000 {
+                assert_eq!(queue.take(), Some((n, n * 2)));
+            }
+        }
+    }
+
+    mod insert_with_deadline {
+        use std::thread;
+        use std::time::{Duration, Instant};
+
+        use crate::queue::Queue;
+        use crate::StoreMut;
+
+        #[test]
+        fn handles_future() {
+            let mut queue = Queue::default();
+            let deadline = Instant::now() + Duration::from_millis(50);
+            queue.insert_with_deadline("a", 1, deadline);
+            assert_eq!(queue.get_deadline(&"a"), Some(deadline));
+            assert_eq!(
+                queue.iter_all().collect::<Vec<_>>(), // fmt
+                vec![(&"a", &1)]
+            );
+            assert_eq!(queue.take(), None);
+            while Instant::now() < deadline {
+                thread::sleep(Duration::from_millis(10));
+            }
+            assert_eq!(queue.take(), Some(("a", 1)));
+        }
+
+        #[test]
+        fn handles_existing() {
+            let mut queue = Queue::default();
+            queue.insert("a", 1);
+            let deadline = Instant::now() + Duration::from_secs(60);
+            assert_eq!(queue.insert_with_deadline("a", 2, deadline), Some(1));
+            assert_eq!(queue.get_deadline(&"a"), Some(deadline));
+            assert_eq!(queue.len_all(), 1);
+            assert_eq!(queue.take(), None);
+        }
+    }
+
+    mod time_until_next {
+        use std::thread;
+        use std::time::{Duration, Instant};
+
+        use crate::queue::Queue;
+
+        #[test]
+        fn handles_future() {
+            let mut queue = Queue::default();
+            let deadline = Instant::now() + Duration::from_millis(50);
+            queue.insert_with_deadline("a", 1, deadline);
+            let duration = queue.time_until_next().unwrap();
+            assert!(duration > Duration::ZERO);
+            assert!(duration < Duration::from_millis(50));
+            while Instant::now() < deadline {
+                thread::sleep(Duration::from_millis(10));
+            }
+            assert_eq!(queue.time_until_next(), Some(Duration::ZERO));
+        }
+
+        #[test]
+        fn handles_empty() {
+            let queue = Queue::<&str, i32>::default();
+            assert_eq!(queue.time_until_next(), None);
+        }
+    }
+
+    mod has_due {
+        use std::time::{Duration, Instant};
+
+        use crate::queue::Queue;
+
+        #[test]
+        fn handles_empty() {
+            let queue = Queue::<&str, i32>::default();
+            assert!(!queue.has_due());
+        }
+
+        #[test]
+        fn handles_future() {
+            let mut queue = Queue::default();
+            let deadline = Instant::now() + Duration::from_secs(60);
+            queue.insert_with_deadline("a", 1, deadline);
+            queue.insert_with_deadline("b", 2, deadline);
+            assert!(!queue.has_due());
+        }
+
+        #[test]
+        fn handles_past() {
+            let mut queue = Queue::default();
+            let deadline = Instant::now() + Duration::from_secs(60);
+            queue.insert_with_deadline("a", 1, deadline);
+            queue.insert_with_deadline("b", 2, Instant::now());
+            assert!(queue.has_due());
+        }
+    }
+
+    mod next_key {
+        use std::time::{Duration, Instant};
+
+        use crate::queue::Queue;
+
+        #[test]
+        fn handles_earliest() {
+            let mut queue = Queue::default();
+            let deadline = Instant::now();
+            let earlier = deadline.checked_sub(Duration::from_secs(1)).unwrap();
+            queue.insert_with_deadline("a", 1, deadline);
+            queue.insert_with_deadline("b", 2, earlier);
+            assert_eq!(queue.next_key(), Some(&"b"));
+            assert_eq!(queue.len_all(), 2);
+        }
+
+        #[test]
+        fn handles_future() {
+            let mut queue = Queue::default();
+            let deadline = Instant::now() + Duration::from_secs(60);
+            queue.insert_with_deadline("a", 1, deadline);
+            assert_eq!(queue.next_key(), None);
+        }
+
+        #[test]
+        fn handles_empty() {
+            let queue = Queue::<&str, i32>::default();
+            assert_eq!(queue.next_key(), None);
+        }
+    }
+
+    mod drain_due_into {
+        use std::thread;
+        use std::time::{Duration, Instant};
+
+        use crate::queue::Queue;
+        use crate::StoreMut;
+
+        #[test]
+        fn handles_reuse() {
+            let mut queue = Queue::default();
+            let deadline = Instant::now() + Duration::from_millis(50);
+            queue.insert("a", 1);
+            queue.insert("b", 2);
+            queue.insert_with_deadline("c", 3, deadline);
+            let mut buf = Vec::new();
+            assert_eq!(queue.drain_due_into(&mut buf), 2);
+            assert_eq!(buf, Vec::from([("a", 1), ("b", 2)]));
+            buf.clear();
+            while Instant::now() < deadline {
+                thread::sleep(Duration::from_millis(10));
+            }
+            assert_eq!(queue.drain_due_into(&mut buf), 1);
+            assert_eq!(buf, Vec::from([("c", 3)]));
+            assert_eq!(queue.len_all(), 0);
+        }
+
+        #[test]
+        fn handles_append() {
+            let mut queue = Queue::default();
+            queue.insert("a", 1);
+            let mut buf = Vec::from([("z", 0)]);
+            assert_eq!(queue.drain_due_into(&mut buf), 1);
+            assert_eq!(buf, Vec::from([("z", 0), ("a", 1)]));
+            assert_eq!(queue.drain_due_into(&mut buf), 0);
+        }
+    }
+
+    mod take {
+        use std::time::Instant;
+
+        use crate::queue::Queue;
+
+        #[test]
+        fn handles_equal_deadline() {
+            let mut queue = Queue::default();
+            let deadline = Instant::now();
+            for n in 0..1_000 {
+                queue.insert_with_deadline(n, n, deadline);
+            }
+            for n in 0..1_000 {
+                assert_eq!(queue.take(), Some((n, n)));
+            }
+            assert_eq!(queue.take(), None);
+        }
+
+        #[test]
+        fn handles_set_deadline() {
+            let mut queue = Queue::default();
+            let deadline = Instant::now();
+            queue.insert_with_deadline("a", 1, deadline);
+            queue.insert_with_deadline("b", 2, deadline);
+            queue.set_deadline(&"a", deadline);
+            assert_eq!(
+                queue.iter_all().collect::<Vec<_>>(),
+                Vec::from([(&"b", &2), (&"a", &1)])
+            );
+            assert_eq!(queue.check_invariants(), Ok(()));
+        }
+    }
+
+    mod retain_due {
+        use std::time::{Duration, Instant};
+
+        use crate::queue::Queue;
+        use crate::{Store, StoreMut};
+
+        #[test]
+        fn handles_mixed() {
+            let mut queue = Queue::default();
+            let deadline = Instant::now() + Duration::from_secs(60);
+            queue.insert("a", 1);
+            queue.insert("b", 2);
+            queue.insert("c", 3);
+            queue.insert("d", 4);
+            queue.insert_with_deadline("e", 5, deadline);
+            queue.retain_due(|_, value| *value % 2 != 0);
+            assert_eq!(
+                queue.iter_all().collect::<Vec<_>>(),
+                Vec::from([(&"a", &1), (&"c", &3), (&"e", &5)])
+            );
+            assert_eq!(queue.get_deadline(&"e"), Some(deadline));
+            assert_eq!(queue.check_invariants(), Ok(()));
+        }
+
+        #[test]
+        fn handles_equal_deadline() {
+            let mut queue = Queue::default();
+            let deadline = Instant::now();
+            queue.insert_with_deadline("a", 1, deadline);
+            queue.insert_with_deadline("b", 2, deadline);
+            queue.retain_due(|_, value| *value % 2 != 0);
+            assert_eq!(queue.get(&"a"), Some(&1));
+            assert_eq!(queue.get(&"b"), None);
+        }
+
+        #[test]
+        fn handles_mutation() {
+            let mut queue = Queue::default();
+            queue.insert("a", 1);
+            queue.retain_due(|_, value| {
+                *value += 1;
+                true
+            });
+            assert_eq!(queue.take(), Some(("a", 2)));
+        }
+    }
+
+    mod remove_where {
+        use std::time::{Duration, Instant};
+
+        use crate::queue::Queue;
+        use crate::{Store, StoreMut};
+
+        #[test]
+        fn handles_mixed() {
+            let mut queue = Queue::default();
+            let deadline = Instant::now() + Duration::from_secs(60);
+            queue.insert("a", 1);
+            queue.insert("b", 2);
+            queue.insert("c", 3);
+            queue.insert_with_deadline("d", 4, deadline);
+            queue.insert_with_deadline("e", 5, deadline);
+            assert_eq!(queue.remove_where(|_, value| *value % 2 == 0), 2);
+            assert_eq!(queue.len(), 3);
+            assert_eq!(
+                queue.iter_all().collect::<Vec<_>>(),
+                Vec::from([(&"a", &1), (&"c", &3), (&"e", &5)])
+            );
+            assert_eq!(queue.check_invariants(), Ok(()));
+        }
+
+        #[test]
+        fn handles_reuse() {
+            let mut queue = Queue::default();
+            queue.insert("a", 1);
+            queue.insert("b", 2);
+            assert_eq!(queue.remove_where(|key, _| *key == "a"), 1);
+            queue.insert("c", 3);
+            assert_eq!(queue.take(), Some(("b", 2)));
+            assert_eq!(queue.take(), Some(("c", 3)));
+            assert_eq!(queue.check_invariants(), Ok(()));
+        }
+
+        #[test]
+        fn handles_none() {
+            let mut queue = Queue::default();
+            queue.insert("a", 1);
+            assert_eq!(queue.remove_where(|_, _| false), 0);
+            assert_eq!(queue.len(), 1);
+        }
+    }
+
+    mod get_or_insert_with {
+        use std::cell::Cell;
+
+        use crate::queue::Queue;
+        use crate::{Store, StoreMutRef};
+
+        #[test]
+        fn handles_counter() {
+            let count = Cell::new(0);
+            let init = || {
+                count.set(count.get() + 1);
+                1
+            };
+            let mut queue = Queue::default();
+            *queue.get_or_insert_with(&"a", init) += 1;
+            *queue.get_or_insert_with(&"a", init) += 1;
+            assert_eq!(queue.get(&"a"), Some(&3));
+            assert_eq!(queue.len(), 1);
+            assert_eq!(count.get(), 1);
+        }
+    }
+
+    mod transform {
+        use std::time::{Duration, Instant};
+
+        use crate::queue::Queue;
+        use crate::{StoreMut, StoreTransform};
+
+        #[test]
+        fn handles_deadline() {
+            let mut queue = Queue::default();
+            let deadline = Instant::now() + Duration::from_secs(60);
+            queue.insert("a", 1);
+            queue.insert_with_deadline("b", 2, deadline);
+            queue.transform(|_, value| *value *= 10);
+            assert_eq!(
+                queue.iter_all().collect::<Vec<_>>(),
+                Vec::from([(&"a", &10), (&"b", &20)])
+            );
+        }
+    }
+}
@@ -26,6 +26,7 @@
 //! Queue item.
 
 use std::cmp::Ordering;
+use std::sync::atomic::{self, AtomicU64};
 use std::time::Instant;
 
 // ----------------------------------------------------------------------------
@@ -40,7 +41,9 @@ use std::time::Instant;
 /// Items must only be considered for processing when their deadline has passed,
 /// which is exactly what [`Queue::take`][] ensures. This allows to implement
 /// timers and intervals in an efficient manner. In case two items have the
-/// same deadline, order is undefined, but this doesn't matter for us.
+/// same deadline, which is common when they're created in a tight loop, ties
+/// are broken by a monotonically increasing sequence number, so items are
+/// ordered by the time they were created or their deadline was last set.
 ///
 /// Note that mutable data needs to be stored outside of the queue, as items are
 /// immutable. The built-in [`Queue`][] uses a [`Slab`][] for this matter.
@@ -52,6 +55,8 @@ use std::time::Instant;
 pub struct Item<T = usize> {
     /// Deadline.
     deadline: Instant,
+    /// Sequence number.
+    sequence: u64,
     /// Associated data.
     data: T,
 }
@@ -73,11 +78,35 @@ impl<T> Item<T> {
     /// ```
     #[must_use]
     pub fn new(data: T) -> Self {
-        Self { deadline: Instant::now(), data }
+        Self::with_deadline(data, Instant::now())
+    }
+
+    /// Creates a queue item with the given deadline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use zrx_store::queue::Item;
+    ///
+    /// // Create queue item with deadline
+    /// let deadline = Instant::now() + Duration::from_secs(60);
+    /// let item = Item::with_deadline(42, deadline);
+    /// ```
+    #[must_use]
+    pub fn with_deadline(data: T, deadline: Instant) -> Self {
+        Self {
+            deadline,
+            sequence: next_sequence(),
+            data,
+        }
     }
 
     /// Updates the deadline of the queue item.
     ///
+    /// The sequence number is renewed as well, so that among items with equal
+    /// deadlines, the item whose deadline was set last is ordered last.
+    ///
     /// # Examples
     ///
     /// ```
@@ -91,6 +120,7 @@ impl<T> Item<T> {
     #[inline]
     pub fn set_deadline(&mut self, deadline: Instant) {
         self.deadline = deadline;
+        self.sequence = next_sequence();
     }
 }
 
@@ -181,6 +211,18 @@ where
     /// assert!(a <= b);
     /// ```
     fn cmp(&self, other: &Self) -> Ordering {
-        self.deadline.cmp(&other.deadline)
+        self.deadline
+            .cmp(&other.deadline)
+            .then_with(|| self.sequence.cmp(&other.sequence))
     }
 }
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns the next sequence number for breaking ties between deadlines.
+fn next_sequence() -> u64 {
+    static SEQUENCE: AtomicU64 = AtomicU64::new(0);
+    SEQUENCE.fetch_add(1, atomic::Ordering::Relaxed)
+}
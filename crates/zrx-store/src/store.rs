@@ -53,11 +53,16 @@ use key::Key;
 ///
 /// - [`StoreMut`]: Mutable store
 /// - [`StoreMutRef`]: Mutable store that can return mutable references
+/// - [`StoreKeyValue`]: Immutable store that can return stored keys
 /// - [`StoreIterable`]: Immutable store that is iterable
 /// - [`StoreIterableMut`]: Mutable store that is iterable
 /// - [`StoreKeys`]: Immutable store that is iterable over its keys
 /// - [`StoreValues`]: Immutable store that is iterable over its values
 /// - [`StoreRange`]: Immutable store that is iterable over a range
+/// - [`StoreSeek`]: Immutable store that is iterable from a given key
+/// - [`StoreStats`]: Immutable store that reports its allocated capacity
+/// - [`StoreSnapshot`]: Immutable store that can be copied into a vector
+/// - [`StoreTransform`]: Mutable store that can transform values in place
 ///
 /// This trait is implemented for [`HashMap`][] and [`BTreeMap`][], as well as
 /// all of the store [`decorators`][] that allow to wrap stores with additional
@@ -114,6 +119,37 @@ where
     }
 }
 
+/// Immutable store that can return stored keys.
+///
+/// This trait extends [`Store`], adding the capability to obtain a reference
+/// to the key as stored alongside the value, which is useful when the key is
+/// expensive to clone, or carries information not considered for equality.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use zrx_store::{StoreKeyValue, StoreMut};
+///
+/// // Create store and initial state
+/// let mut store = HashMap::new();
+/// store.insert("key", 42);
+///
+/// // Obtain references to key and value
+/// let entry = store.get_key_value(&"key");
+/// assert_eq!(entry, Some((&"key", &42)));
+/// ```
+pub trait StoreKeyValue<K, V>: Store<K, V>
+where
+    K: Key,
+{
+    /// Returns references to the key and value identified by the key.
+    fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Key;
+}
+
 /// Mutable store.
 ///
 /// This trait extends [`Store`], requiring further additional mutable methods
@@ -162,8 +198,31 @@ where
         K: Borrow<Q>,
         Q: Key;
 
+    /// Removes the values identified by the keys.
+    ///
+    /// The default implementation removes each key individually, and returns
+    /// the number of values that were actually removed. Stores that can remove
+    /// multiple keys more efficiently should override this method.
+    #[inline]
+    fn remove_all<I>(&mut self, keys: I) -> usize
+    where
+        I: IntoIterator<Item = K>,
+    {
+        let iter = keys.into_iter();
+        iter.filter(|key| self.remove(key).is_some()).count()
+    }
+
     /// Clears the store, removing all items.
     fn clear(&mut self);
+
+    /// Shrinks the capacity of the store as much as possible.
+    ///
+    /// The default implementation does nothing, as not all stores have a
+    /// notion of spare capacity, e.g., [`BTreeMap`][].
+    ///
+    /// [`BTreeMap`]: std::collections::BTreeMap
+    #[inline]
+    fn shrink_to_fit(&mut self) {}
 }
 
 /// Mutable store that can return mutable references.
@@ -199,6 +258,26 @@ where
     fn get_or_insert_default(&mut self, key: &K) -> &mut V
     where
         V: Default;
+
+    /// Returns a mutable reference to the value or creates it with the given
+    /// function, which is only invoked if the key is absent.
+    ///
+    /// The default implementation checks for presence of the key, inserts the
+    /// value if necessary, and looks it up again. Stores that can do this in
+    /// a single lookup, e.g., through an entry API, should override it.
+    #[inline]
+    fn get_or_insert_with<F>(&mut self, key: &K, f: F) -> &mut V
+    where
+        Self: StoreMut<K, V>,
+        F: FnOnce() -> V,
+    {
+        if !self.contains_key(key) {
+            self.insert(key.clone(), f());
+        }
+
+        // We can safely use expect here, as the key is present
+        self.get_mut(key).expect("invariant")
+    }
 }
 
 /// Immutable store that is iterable.
@@ -300,6 +379,20 @@ where
 
     /// Creates an iterator over the keys of a store.
     fn keys(&self) -> Self::Keys<'_>;
+
+    /// Returns the keys of a store in ascending order.
+    ///
+    /// The default implementation collects and sorts the keys, which allows to
+    /// obtain a deterministic order from stores that don't guarantee an order,
+    /// e.g., for logging or serialization, without the need for [`Ordered`][].
+    ///
+    /// [`Ordered`]: crate::store::decorator::Ordered
+    #[inline]
+    fn keys_sorted(&self) -> Vec<&K> {
+        let mut keys: Vec<_> = self.keys().collect();
+        keys.sort_unstable();
+        keys
+    }
 }
 
 /// Immutable store that is iterable over its values.
@@ -370,6 +463,157 @@ where
     fn range<R>(&self, range: R) -> Self::Range<'_>
     where
         R: RangeBounds<K>;
+
+    /// Creates an iterator over the keys in a range of a store.
+    #[inline]
+    fn range_keys<'a, R>(&'a self, range: R) -> impl Iterator<Item = &'a K>
+    where
+        R: RangeBounds<K>,
+        K: 'a,
+        V: 'a,
+    {
+        self.range(range).map(|(key, _)| key)
+    }
+
+    /// Creates an iterator over the values in a range of a store.
+    #[inline]
+    fn range_values<'a, R>(&'a self, range: R) -> impl Iterator<Item = &'a V>
+    where
+        R: RangeBounds<K>,
+        K: 'a,
+        V: 'a,
+    {
+        self.range(range).map(|(_, value)| value)
+    }
+}
+
+/// Immutable store that is iterable from a given key.
+///
+/// This trait extends [`StoreIterable`], adding the capability to resume the
+/// iteration at the position of a given key, which is useful for pagination,
+/// as ordered stores don't need to be re-scanned from the start.
+///
+/// # Examples
+///
+/// ```
+/// use zrx_store::decorator::Ordered;
+/// use zrx_store::{StoreMut, StoreSeek};
+///
+/// // Create store and initial state
+/// let mut store = Ordered::default();
+/// store.insert("a", 42);
+/// store.insert("b", 84);
+///
+/// // Create iterator over the store
+/// for (key, value) in store.iter_from(&"b") {
+///     println!("{key}: {value}");
+/// }
+/// ```
+pub trait StoreSeek<K, V>: StoreIterable<K, V>
+where
+    K: Key,
+{
+    /// Creates an iterator over the items of a store, starting at the key.
+    ///
+    /// If the key is absent, the returned iterator is empty.
+    fn iter_from<'a>(&'a self, key: &K) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a;
+}
+
+/// Immutable store that reports its allocated capacity.
+///
+/// This trait extends [`Store`], adding the capability to introspect the
+/// number of items a store can hold without reallocating, which is useful for
+/// capacity planning, e.g., to decide when to shrink a store. Decorators report
+/// the capacity of the most constrained of their underlying data structures.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use zrx_store::{StoreMut, StoreStats};
+///
+/// // Create store and initial state
+/// let mut store = HashMap::new();
+/// store.insert("key", 42);
+///
+/// // Obtain capacity of store
+/// assert!(StoreStats::capacity(&store) >= 1);
+/// ```
+pub trait StoreStats<K, V>: Store<K, V>
+where
+    K: Key,
+{
+    /// Returns the number of items the store can hold without reallocating.
+    fn capacity(&self) -> usize;
+}
+
+/// Immutable store that can be copied into a vector.
+///
+/// This trait extends [`StoreIterable`], adding the capability to create a
+/// snapshot of all items without consuming the store. Items are returned in
+/// iteration order, which means sorted order for ordered decorators. It's
+/// implemented for all iterable stores via a blanket implementation.
+///
+/// # Examples
+///
+/// ```
+/// use zrx_store::decorator::Ordered;
+/// use zrx_store::{StoreMut, StoreSnapshot};
+///
+/// // Create store and initial state
+/// let mut store = Ordered::default();
+/// store.insert("a", 2);
+/// store.insert("b", 1);
+///
+/// // Create snapshot of store
+/// let items = store.to_vec();
+/// assert_eq!(items, [("b", 1), ("a", 2)]);
+/// ```
+pub trait StoreSnapshot<K, V>: StoreIterable<K, V>
+where
+    K: Key,
+{
+    /// Returns a vector with clones of all items, in iteration order.
+    fn to_vec(&self) -> Vec<(K, V)>
+    where
+        V: Clone;
+}
+
+/// Mutable store that can transform values in place.
+///
+/// This trait extends [`StoreMut`], adding the capability to apply a function
+/// to all values in place, without looking up each key individually. It's not
+/// implemented for decorators that order by values, like [`Ordered`][] and
+/// [`Indexed`][], since mutating values in place would break their ordering.
+///
+/// [`Indexed`]: crate::store::decorator::Indexed
+/// [`Ordered`]: crate::store::decorator::Ordered
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use zrx_store::{Store, StoreMut, StoreTransform};
+///
+/// // Create store and initial state
+/// let mut store = HashMap::new();
+/// store.insert("key", 42);
+///
+/// // Transform all values in place
+/// store.transform(|_, value| *value *= 2);
+/// assert_eq!(store.get(&"key"), Some(&84));
+/// ```
+pub trait StoreTransform<K, V>: StoreMut<K, V>
+where
+    K: Key,
+{
+    /// Applies the given function to all items of the store.
+    fn transform<F>(&mut self, f: F)
+    where
+        F: FnMut(&K, &mut V);
 }
 
 // ----------------------------------------------------------------------------
@@ -424,3 +668,19 @@ where
 impl<K, V, T> StoreIntoIterator<K, V> for T
 where
     T: IntoIterator<Item = (K, V)> {}
+
+impl<K, V, T> StoreSnapshot<K, V> for T
+where
+    K: Key,
+    T: StoreIterable<K, V>,
+{
+    #[inline]
+    fn to_vec(&self) -> Vec<(K, V)>
+    where
+        V: Clone,
+    {
+        self.iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+}
@@ -30,7 +30,9 @@ use std::collections::{BTreeMap, HashMap};
 use std::hash::BuildHasher;
 
 use crate::store::key::Key;
-use crate::store::{Store, StoreMut, StoreMutRef};
+use crate::store::{
+    Store, StoreKeyValue, StoreMut, StoreMutRef, StoreStats, StoreTransform,
+};
 
 mod iter;
 
@@ -100,6 +102,37 @@ where
     }
 }
 
+impl<K, V, S> StoreKeyValue<K, V> for HashMap<K, V, S>
+where
+    K: Key,
+    S: BuildHasher,
+{
+    /// Returns references to the key and value identified by the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::{StoreKeyValue, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("key", 42);
+    ///
+    /// // Obtain references to key and value
+    /// let entry = store.get_key_value(&"key");
+    /// assert_eq!(entry, Some((&"key", &42)));
+    /// ```
+    #[inline]
+    fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        HashMap::get_key_value(self, key)
+    }
+}
+
 impl<K, V, S> StoreMut<K, V> for HashMap<K, V, S>
 where
     K: Key,
@@ -230,6 +263,27 @@ where
     fn clear(&mut self) {
         HashMap::clear(self);
     }
+
+    /// Shrinks the capacity of the store as much as possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::with_capacity(64);
+    /// store.insert("key", 42);
+    ///
+    /// // Shrink store
+    /// store.shrink_to_fit();
+    /// assert!(store.capacity() < 64);
+    /// ```
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        HashMap::shrink_to_fit(self);
+    }
 }
 
 impl<K, V, S> StoreMutRef<K, V> for HashMap<K, V, S>
@@ -285,6 +339,88 @@ where
     {
         HashMap::entry(self, key.clone()).or_default()
     }
+
+    /// Returns a mutable reference to the value or creates it with the given
+    /// function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::StoreMutRef;
+    ///
+    /// // Create store
+    /// let mut store = HashMap::new();
+    ///
+    /// // Obtain mutable reference to value
+    /// let value = store.get_or_insert_with(&"key", || 42);
+    /// assert_eq!(value, &mut 42);
+    /// ```
+    #[inline]
+    fn get_or_insert_with<F>(&mut self, key: &K, f: F) -> &mut V
+    where
+        F: FnOnce() -> V,
+    {
+        HashMap::entry(self, key.clone()).or_insert_with(f)
+    }
+}
+
+impl<K, V, S> StoreStats<K, V> for HashMap<K, V, S>
+where
+    K: Key,
+    S: BuildHasher,
+{
+    /// Returns the number of items the store can hold without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::{StoreMut, StoreStats};
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("key", 42);
+    ///
+    /// // Obtain capacity of store
+    /// assert!(StoreStats::capacity(&store) >= 1);
+    /// ```
+    #[inline]
+    fn capacity(&self) -> usize {
+        HashMap::capacity(self)
+    }
+}
+
+impl<K, V, S> StoreTransform<K, V> for HashMap<K, V, S>
+where
+    K: Key,
+    S: BuildHasher,
+{
+    /// Applies the given function to all items of the store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::{Store, StoreMut, StoreTransform};
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("key", 42);
+    ///
+    /// // Transform all values in place
+    /// store.transform(|_, value| *value *= 2);
+    /// assert_eq!(store.get(&"key"), Some(&84));
+    /// ```
+    #[inline]
+    fn transform<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V),
+    {
+        for (key, value) in HashMap::iter_mut(self) {
+            f(key, value);
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -350,6 +486,36 @@ where
     }
 }
 
+impl<K, V> StoreKeyValue<K, V> for BTreeMap<K, V>
+where
+    K: Key,
+{
+    /// Returns references to the key and value identified by the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use zrx_store::{StoreKeyValue, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = BTreeMap::new();
+    /// store.insert("key", 42);
+    ///
+    /// // Obtain references to key and value
+    /// let entry = store.get_key_value(&"key");
+    /// assert_eq!(entry, Some((&"key", &42)));
+    /// ```
+    #[inline]
+    fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        BTreeMap::get_key_value(self, key)
+    }
+}
+
 impl<K, V> StoreMut<K, V> for BTreeMap<K, V>
 where
     K: Key,
@@ -533,6 +699,89 @@ where
     {
         BTreeMap::entry(self, key.clone()).or_default()
     }
+
+    /// Returns a mutable reference to the value or creates it with the given
+    /// function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use zrx_store::StoreMutRef;
+    ///
+    /// // Create store
+    /// let mut store = BTreeMap::new();
+    ///
+    /// // Obtain mutable reference to value
+    /// let value = store.get_or_insert_with(&"key", || 42);
+    /// assert_eq!(value, &mut 42);
+    /// ```
+    #[inline]
+    fn get_or_insert_with<F>(&mut self, key: &K, f: F) -> &mut V
+    where
+        F: FnOnce() -> V,
+    {
+        BTreeMap::entry(self, key.clone()).or_insert_with(f)
+    }
+}
+
+impl<K, V> StoreStats<K, V> for BTreeMap<K, V>
+where
+    K: Key,
+{
+    /// Returns the number of items the store can hold without reallocating.
+    ///
+    /// Since [`BTreeMap`] allocates nodes on demand and has no notion of spare
+    /// capacity, this method returns the number of items in the store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use zrx_store::{StoreMut, StoreStats};
+    ///
+    /// // Create store and initial state
+    /// let mut store = BTreeMap::new();
+    /// store.insert("key", 42);
+    ///
+    /// // Obtain capacity of store
+    /// assert_eq!(store.capacity(), 1);
+    /// ```
+    #[inline]
+    fn capacity(&self) -> usize {
+        BTreeMap::len(self)
+    }
+}
+
+impl<K, V> StoreTransform<K, V> for BTreeMap<K, V>
+where
+    K: Key,
+{
+    /// Applies the given function to all items of the store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use zrx_store::{Store, StoreMut, StoreTransform};
+    ///
+    /// // Create store and initial state
+    /// let mut store = BTreeMap::new();
+    /// store.insert("key", 42);
+    ///
+    /// // Transform all values in place
+    /// store.transform(|_, value| *value *= 2);
+    /// assert_eq!(store.get(&"key"), Some(&84));
+    /// ```
+    #[inline]
+    fn transform<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V),
+    {
+        for (key, value) in BTreeMap::iter_mut(self) {
+            f(key, value);
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -552,3 +801,112 @@ where
         true
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod get_or_insert_with {
+        use std::cell::Cell;
+        use std::collections::{BTreeMap, HashMap};
+
+        use crate::StoreMutRef;
+
+        #[test]
+        fn handles_hash_map() {
+            let count = Cell::new(0);
+            let init = || {
+                count.set(count.get() + 1);
+                1
+            };
+            let mut store = HashMap::new();
+            *store.get_or_insert_with(&"a", init) += 1;
+            *store.get_or_insert_with(&"a", init) += 1;
+            assert_eq!(store.get(&"a"), Some(&3));
+            assert_eq!(count.get(), 1);
+        }
+
+        #[test]
+        fn handles_btree_map() {
+            let count = Cell::new(0);
+            let init = || {
+                count.set(count.get() + 1);
+                1
+            };
+            let mut store = BTreeMap::new();
+            *store.get_or_insert_with(&"a", init) += 1;
+            *store.get_or_insert_with(&"a", init) += 1;
+            assert_eq!(store.get(&"a"), Some(&3));
+            assert_eq!(count.get(), 1);
+        }
+    }
+
+    mod keys_sorted {
+        use std::collections::HashMap;
+
+        use crate::StoreKeys;
+
+        #[test]
+        fn handles_hash_map() {
+            let mut store = HashMap::new();
+            for key in ["d", "a", "e", "c", "b"] {
+                store.insert(key, 0);
+            }
+            assert_eq!(
+                store.keys_sorted(),
+                Vec::from([&"a", &"b", &"c", &"d", &"e"])
+            );
+        }
+    }
+
+    mod range_keys {
+        use std::collections::BTreeMap;
+
+        use crate::StoreRange;
+
+        #[test]
+        fn handles_btree_map() {
+            let store =
+                BTreeMap::from([("a", 1), ("b", 2), ("c", 3), ("d", 4)]);
+            assert_eq!(
+                store.range_keys("b".."d").collect::<Vec<_>>(),
+                Vec::from([&"b", &"c"])
+            );
+        }
+    }
+
+    mod range_values {
+        use std::collections::BTreeMap;
+
+        use crate::StoreRange;
+
+        #[test]
+        fn handles_btree_map() {
+            let store =
+                BTreeMap::from([("a", 1), ("b", 2), ("c", 3), ("d", 4)]);
+            assert_eq!(
+                store.range_values("b".."d").collect::<Vec<_>>(),
+                Vec::from([&2, &3])
+            );
+        }
+    }
+
+    mod transform {
+        use std::collections::HashMap;
+
+        use crate::StoreTransform;
+
+        #[test]
+        fn handles_values() {
+            let mut store = HashMap::new();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            store.transform(|_, value| *value *= 2);
+            assert_eq!(store, HashMap::from([("a", 8), ("b", 4), ("c", 6)]));
+        }
+    }
+}
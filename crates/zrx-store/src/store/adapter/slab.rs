@@ -29,7 +29,9 @@ use slab::Slab;
 use std::borrow::Borrow;
 
 use crate::store::key::Key;
-use crate::store::{Store, StoreMut, StoreMutRef};
+use crate::store::{
+    Store, StoreKeyValue, StoreMut, StoreMutRef, StoreStats, StoreTransform,
+};
 
 mod iter;
 
@@ -102,6 +104,38 @@ where
     }
 }
 
+impl<K, V> StoreKeyValue<K, V> for Slab<(K, V)>
+where
+    K: Key,
+{
+    /// Returns references to the key and value identified by the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use slab::Slab;
+    /// use zrx_store::{StoreKeyValue, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Slab::new();
+    /// StoreMut::insert(&mut store, "key", 42);
+    ///
+    /// // Obtain references to key and value
+    /// let entry = StoreKeyValue::get_key_value(&store, &"key");
+    /// assert_eq!(entry, Some((&"key", &42)));
+    /// ```
+    #[inline]
+    fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        Slab::iter(self).find_map(|(_, (check, value))| {
+            (check.borrow() == key).then_some((check, value))
+        })
+    }
+}
+
 impl<K, V> StoreMut<K, V> for Slab<(K, V)>
 where
     K: Key,
@@ -205,6 +239,27 @@ where
     fn clear(&mut self) {
         Slab::clear(self);
     }
+
+    /// Shrinks the capacity of the store as much as possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use slab::Slab;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Slab::with_capacity(64);
+    /// StoreMut::insert(&mut store, "key", 42);
+    ///
+    /// // Shrink store
+    /// StoreMut::shrink_to_fit(&mut store);
+    /// assert!(store.capacity() < 64);
+    /// ```
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        Slab::shrink_to_fit(self);
+    }
 }
 
 impl<K, V> StoreMutRef<K, V> for Slab<(K, V)>
@@ -260,10 +315,141 @@ where
         V: Default,
     {
         let index = Slab::iter(self)
-            .position(|(_, (check, _))| check.borrow() == key)
+            .find_map(|(index, (check, _))| {
+                (check.borrow() == key).then_some(index)
+            })
             .unwrap_or_else(|| Slab::insert(self, (key.clone(), V::default())));
 
         // Return mutable reference
         &mut self[index].1
     }
+
+    /// Returns a mutable reference to the value or creates it with the given
+    /// function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use slab::Slab;
+    /// use zrx_store::StoreMutRef;
+    ///
+    /// // Create store
+    /// let mut store = Slab::new();
+    ///
+    /// // Obtain mutable reference to value
+    /// let value = StoreMutRef::get_or_insert_with(&mut store, &"key", || 42);
+    /// assert_eq!(value, &mut 42);
+    /// ```
+    #[inline]
+    fn get_or_insert_with<F>(&mut self, key: &K, f: F) -> &mut V
+    where
+        F: FnOnce() -> V,
+    {
+        let index = Slab::iter(self)
+            .find_map(|(index, (check, _))| {
+                (check.borrow() == key).then_some(index)
+            })
+            .unwrap_or_else(|| Slab::insert(self, (key.clone(), f())));
+
+        // Return mutable reference
+        &mut self[index].1
+    }
+}
+
+impl<K, V> StoreStats<K, V> for Slab<(K, V)>
+where
+    K: Key,
+{
+    /// Returns the number of items the store can hold without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use slab::Slab;
+    /// use zrx_store::{StoreMut, StoreStats};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Slab::new();
+    /// StoreMut::insert(&mut store, "key", 42);
+    ///
+    /// // Obtain capacity of store
+    /// assert!(StoreStats::capacity(&store) >= 1);
+    /// ```
+    #[inline]
+    fn capacity(&self) -> usize {
+        Slab::capacity(self)
+    }
+}
+
+impl<K, V> StoreTransform<K, V> for Slab<(K, V)>
+where
+    K: Key,
+{
+    /// Applies the given function to all items of the store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use slab::Slab;
+    /// use zrx_store::{Store, StoreMut, StoreTransform};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Slab::new();
+    /// StoreMut::insert(&mut store, "key", 42);
+    ///
+    /// // Transform all values in place
+    /// store.transform(|_, value| *value *= 2);
+    /// assert_eq!(Store::get(&store, &"key"), Some(&84));
+    /// ```
+    #[inline]
+    fn transform<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V),
+    {
+        for (_, (key, value)) in Slab::iter_mut(self) {
+            f(key, value);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod get_or_insert_default {
+        use slab::Slab;
+
+        use crate::{Store, StoreMut, StoreMutRef};
+
+        #[test]
+        fn handles_vacant_slot() {
+            let mut store = Slab::new();
+            StoreMut::insert(&mut store, "a", 1);
+            StoreMut::insert(&mut store, "b", 2);
+            StoreMut::remove(&mut store, &"a");
+            *StoreMutRef::get_or_insert_default(&mut store, &"b") += 1;
+            assert_eq!(Store::get(&store, &"b"), Some(&3));
+            assert_eq!(Store::len(&store), 1);
+        }
+    }
+
+    mod get_or_insert_with {
+        use slab::Slab;
+
+        use crate::{Store, StoreMut, StoreMutRef};
+
+        #[test]
+        fn handles_vacant_slot() {
+            let mut store = Slab::new();
+            StoreMut::insert(&mut store, "a", 1);
+            StoreMut::insert(&mut store, "b", 2);
+            StoreMut::remove(&mut store, &"a");
+            *StoreMutRef::get_or_insert_with(&mut store, &"b", || 0) += 1;
+            assert_eq!(Store::get(&store, &"b"), Some(&3));
+            assert_eq!(Store::len(&store), 1);
+        }
+    }
 }
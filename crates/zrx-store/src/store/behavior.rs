@@ -26,5 +26,9 @@
 //! Store behaviors.
 
 mod delta;
+mod diff;
+mod restore;
 
 pub use delta::StoreDelta;
+pub use diff::{diff, StoreDiff};
+pub use restore::{Checkpoint, StoreRestore};
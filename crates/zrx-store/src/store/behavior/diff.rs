@@ -0,0 +1,174 @@
+// Copyright (c) 2025-2026 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// All contributions are certified under the DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Store diff behavior.
+
+use crate::store::key::Key;
+use crate::store::StoreIterable;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Store diff.
+///
+/// A diff describes the changes between two snapshots of a store, which is
+/// computed by [`diff`]. Added and changed items are listed in the iteration
+/// order of the new store, and removed keys in the order of the old store.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StoreDiff<K, V> {
+    /// Items only present in the new store.
+    pub added: Vec<(K, V)>,
+    /// Keys only present in the old store.
+    pub removed: Vec<K>,
+    /// Items present in both stores, but with different values.
+    pub changed: Vec<(K, V)>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<K, V> StoreDiff<K, V> {
+    /// Returns whether the diff contains no changes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::behavior::diff;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create stores and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("a", 42);
+    ///
+    /// // Compute diff of store with itself
+    /// let diff = diff(&store, &store);
+    /// assert!(diff.is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Computes the diff between two stores.
+///
+/// This function compares an old and a new snapshot of a store, and returns
+/// the items that were added, removed or changed, which is useful when change
+/// tracking isn't available, e.g., for incremental builds. Both stores are
+/// iterated once, and each key is looked up in the other store.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use zrx_store::behavior::diff;
+/// use zrx_store::StoreMut;
+///
+/// // Create stores and initial state
+/// let mut old = HashMap::new();
+/// old.insert("a", 1);
+/// old.insert("b", 2);
+/// let mut new = HashMap::new();
+/// new.insert("b", 3);
+///
+/// // Compute diff between stores
+/// let diff = diff(&old, &new);
+/// assert_eq!(diff.removed, Vec::from(["a"]));
+/// assert_eq!(diff.changed, Vec::from([("b", 3)]));
+/// ```
+pub fn diff<K, V, A, B>(old: &A, new: &B) -> StoreDiff<K, V>
+where
+    K: Key,
+    V: Clone + PartialEq,
+    A: StoreIterable<K, V>,
+    B: StoreIterable<K, V>,
+{
+    let mut added = Vec::new();
+    let mut changed = Vec::new();
+
+    // Determine added and changed items from the new store
+    for (key, value) in new.iter() {
+        match old.get(key) {
+            None => added.push((key.clone(), value.clone())),
+            Some(prior) if prior != value => {
+                changed.push((key.clone(), value.clone()));
+            }
+            Some(_) => {}
+        }
+    }
+
+    // Determine removed keys from the old store
+    let removed = old
+        .iter()
+        .filter(|(key, _)| !new.contains_key(*key))
+        .map(|(key, _)| key.clone())
+        .collect();
+
+    // Return diff
+    StoreDiff { added, removed, changed }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod diff {
+        use std::collections::HashMap;
+
+        use crate::behavior::{diff, StoreDiff};
+
+        #[test]
+        fn handles_changes() {
+            let old = HashMap::from([("a", 1), ("b", 2), ("c", 3)]);
+            let new = HashMap::from([("a", 1), ("b", 4), ("d", 5)]);
+            assert_eq!(
+                diff(&old, &new),
+                StoreDiff {
+                    added: Vec::from([("d", 5)]),
+                    removed: Vec::from(["c"]),
+                    changed: Vec::from([("b", 4)]),
+                }
+            );
+        }
+
+        #[test]
+        fn handles_equal() {
+            let old = HashMap::from([("a", 1), ("b", 2)]);
+            assert!(diff(&old, &old.clone()).is_empty());
+        }
+    }
+}
@@ -0,0 +1,511 @@
+// Copyright (c) 2025-2026 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// All contributions are certified under the DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Store restore behavior.
+
+use slab::Slab;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasher;
+
+use crate::store::comparator::Comparator;
+use crate::store::decorator::{Indexed, Ordered};
+use crate::store::key::Key;
+use crate::store::{StoreMut, StoreSnapshot};
+
+// ----------------------------------------------------------------------------
+// Traits
+// ----------------------------------------------------------------------------
+
+/// Store restore behavior.
+///
+/// Sometimes, we need to perform speculative mutations on a store, and roll
+/// them back on failure. This trait allows to take a [`Checkpoint`] of all
+/// items of a store, and to restore the store to it later on. Restoring clears
+/// the store and re-inserts all items, so decorators like [`Indexed`][] and
+/// [`Ordered`][] rebuild their ordering, and end up in the exact same state.
+///
+/// The checkpoint type is defined by the store, since some stores need to
+/// capture more than their items, e.g., [`Queue`][] also captures deadlines,
+/// including those of items that are not due yet.
+///
+/// [`Indexed`]: crate::store::decorator::Indexed
+/// [`Ordered`]: crate::store::decorator::Ordered
+/// [`Queue`]: crate::queue::Queue
+pub trait StoreRestore<K, V>
+where
+    K: Key,
+{
+    /// Checkpoint type.
+    type Checkpoint;
+
+    /// Creates a checkpoint of all items of the store.
+    fn snapshot(&self) -> Self::Checkpoint
+    where
+        V: Clone;
+
+    /// Restores the store to the given checkpoint.
+    fn restore(&mut self, checkpoint: Self::Checkpoint);
+}
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Store checkpoint.
+///
+/// A checkpoint holds clones of all items of a store at the time it was created
+/// with [`StoreRestore::snapshot`], in iteration order of the store.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Checkpoint<K, V> {
+    /// Items of the store.
+    items: Vec<(K, V)>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<K, V> Checkpoint<K, V> {
+    /// Returns the number of items in the checkpoint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::behavior::StoreRestore;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("key", 42);
+    ///
+    /// // Obtain number of items in checkpoint
+    /// let checkpoint = store.snapshot();
+    /// assert_eq!(checkpoint.len(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns whether the checkpoint is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::behavior::StoreRestore;
+    ///
+    /// // Create store
+    /// let store = HashMap::<&str, i32>::new();
+    ///
+    /// // Ensure checkpoint is empty
+    /// let checkpoint = store.snapshot();
+    /// assert!(checkpoint.is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<K, V, S> StoreRestore<K, V> for HashMap<K, V, S>
+where
+    K: Key,
+    S: BuildHasher,
+{
+    type Checkpoint = Checkpoint<K, V>;
+
+    /// Creates a checkpoint of all items of the store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::behavior::StoreRestore;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("a", 42);
+    ///
+    /// // Create checkpoint of store
+    /// let checkpoint = store.snapshot();
+    /// ```
+    #[inline]
+    fn snapshot(&self) -> Self::Checkpoint
+    where
+        V: Clone,
+    {
+        Checkpoint::from(self.to_vec())
+    }
+
+    /// Restores the store to the given checkpoint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::behavior::StoreRestore;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("a", 42);
+    ///
+    /// // Create checkpoint and mutate store
+    /// let checkpoint = store.snapshot();
+    /// store.insert("b", 84);
+    ///
+    /// // Restore store to checkpoint
+    /// store.restore(checkpoint);
+    /// assert_eq!(Store::len(&store), 1);
+    /// ```
+    #[inline]
+    fn restore(&mut self, checkpoint: Self::Checkpoint) {
+        reinsert(self, checkpoint);
+    }
+}
+
+impl<K, V> StoreRestore<K, V> for BTreeMap<K, V>
+where
+    K: Key,
+{
+    type Checkpoint = Checkpoint<K, V>;
+
+    /// Creates a checkpoint of all items of the store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use zrx_store::behavior::StoreRestore;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = BTreeMap::new();
+    /// store.insert("a", 42);
+    ///
+    /// // Create checkpoint of store
+    /// let checkpoint = store.snapshot();
+    /// ```
+    #[inline]
+    fn snapshot(&self) -> Self::Checkpoint
+    where
+        V: Clone,
+    {
+        Checkpoint::from(self.to_vec())
+    }
+
+    /// Restores the store to the given checkpoint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use zrx_store::behavior::StoreRestore;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = BTreeMap::new();
+    /// store.insert("a", 42);
+    ///
+    /// // Create checkpoint and mutate store
+    /// let checkpoint = store.snapshot();
+    /// store.insert("b", 84);
+    ///
+    /// // Restore store to checkpoint
+    /// store.restore(checkpoint);
+    /// assert_eq!(Store::len(&store), 1);
+    /// ```
+    #[inline]
+    fn restore(&mut self, checkpoint: Self::Checkpoint) {
+        reinsert(self, checkpoint);
+    }
+}
+
+impl<K, V> StoreRestore<K, V> for Slab<(K, V)>
+where
+    K: Key,
+{
+    type Checkpoint = Checkpoint<K, V>;
+
+    /// Creates a checkpoint of all items of the store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use slab::Slab;
+    /// use zrx_store::behavior::StoreRestore;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Slab::new();
+    /// StoreMut::insert(&mut store, "a", 42);
+    ///
+    /// // Create checkpoint of store
+    /// let checkpoint = store.snapshot();
+    /// ```
+    #[inline]
+    fn snapshot(&self) -> Self::Checkpoint
+    where
+        V: Clone,
+    {
+        Checkpoint::from(self.to_vec())
+    }
+
+    /// Restores the store to the given checkpoint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use slab::Slab;
+    /// use zrx_store::behavior::StoreRestore;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Slab::new();
+    /// StoreMut::insert(&mut store, "a", 42);
+    ///
+    /// // Create checkpoint and mutate store
+    /// let checkpoint = store.snapshot();
+    /// StoreMut::insert(&mut store, "b", 84);
+    ///
+    /// // Restore store to checkpoint
+    /// store.restore(checkpoint);
+    /// assert_eq!(Store::len(&store), 1);
+    /// ```
+    #[inline]
+    fn restore(&mut self, checkpoint: Self::Checkpoint) {
+        reinsert(self, checkpoint);
+    }
+}
+
+impl<K, V, S, C> StoreRestore<K, V> for Indexed<K, V, S, C>
+where
+    K: Key,
+    V: Ord,
+    S: StoreMut<K, V>,
+    C: Comparator<V>,
+{
+    type Checkpoint = Checkpoint<K, V>;
+
+    /// Creates a checkpoint of all items of the store, in ordering order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::behavior::StoreRestore;
+    /// use zrx_store::decorator::Indexed;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Indexed::default();
+    /// store.insert("a", 42);
+    ///
+    /// // Create checkpoint of store
+    /// let checkpoint = store.snapshot();
+    /// ```
+    #[inline]
+    fn snapshot(&self) -> Self::Checkpoint
+    where
+        V: Clone,
+    {
+        Checkpoint::from(self.to_vec())
+    }
+
+    /// Restores the store to the given checkpoint, rebuilding the ordering.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::behavior::StoreRestore;
+    /// use zrx_store::decorator::Indexed;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Indexed::default();
+    /// store.insert("a", 42);
+    ///
+    /// // Create checkpoint and mutate store
+    /// let checkpoint = store.snapshot();
+    /// store.insert("b", 84);
+    ///
+    /// // Restore store to checkpoint
+    /// store.restore(checkpoint);
+    /// assert_eq!(store.len(), 1);
+    /// ```
+    #[inline]
+    fn restore(&mut self, checkpoint: Self::Checkpoint) {
+        reinsert(self, checkpoint);
+    }
+}
+
+impl<K, V, S, C> StoreRestore<K, V> for Ordered<K, V, S, C>
+where
+    K: Key,
+    V: Clone + Ord,
+    S: StoreMut<K, V>,
+    C: Comparator<V> + Clone,
+{
+    type Checkpoint = Checkpoint<K, V>;
+
+    /// Creates a checkpoint of all items of the store, in ordering order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::behavior::StoreRestore;
+    /// use zrx_store::decorator::Ordered;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Ordered::default();
+    /// store.insert("a", 42);
+    ///
+    /// // Create checkpoint of store
+    /// let checkpoint = store.snapshot();
+    /// ```
+    #[inline]
+    fn snapshot(&self) -> Self::Checkpoint
+    where
+        V: Clone,
+    {
+        Checkpoint::from(self.to_vec())
+    }
+
+    /// Restores the store to the given checkpoint, rebuilding the ordering.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::behavior::StoreRestore;
+    /// use zrx_store::decorator::Ordered;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Ordered::default();
+    /// store.insert("a", 42);
+    ///
+    /// // Create checkpoint and mutate store
+    /// let checkpoint = store.snapshot();
+    /// store.insert("b", 84);
+    ///
+    /// // Restore store to checkpoint
+    /// store.restore(checkpoint);
+    /// assert_eq!(store.len(), 1);
+    /// ```
+    #[inline]
+    fn restore(&mut self, checkpoint: Self::Checkpoint) {
+        reinsert(self, checkpoint);
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<K, V> From<Vec<(K, V)>> for Checkpoint<K, V> {
+    /// Creates a checkpoint from a vector of items.
+    #[inline]
+    fn from(items: Vec<(K, V)>) -> Self {
+        Self { items }
+    }
+}
+
+impl<K, V> IntoIterator for Checkpoint<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    /// Creates a consuming iterator over the checkpoint.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Clears the store and re-inserts all items of the checkpoint.
+fn reinsert<K, V, S>(store: &mut S, checkpoint: Checkpoint<K, V>)
+where
+    K: Key,
+    S: StoreMut<K, V>,
+{
+    store.clear();
+    for (key, value) in checkpoint {
+        store.insert(key, value);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod restore {
+        use crate::behavior::StoreRestore;
+        use crate::decorator::Indexed;
+        use crate::{StoreIterable, StoreMut, StoreSnapshot};
+
+        #[test]
+        fn handles_indexed() {
+            let mut store = Indexed::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            let checkpoint = store.snapshot();
+            let items = store.to_vec();
+            store.insert("d", 1);
+            store.insert("a", 0);
+            StoreMut::remove(&mut store, &"b");
+            store.restore(checkpoint);
+            assert_eq!(store.to_vec(), items);
+            assert_eq!(
+                store.iter().collect::<Vec<_>>(),
+                Vec::from([(&"b", &2), (&"c", &3), (&"a", &4)])
+            );
+            assert_eq!(store[0], "b");
+            assert_eq!(store.check_invariants(), Ok(()));
+        }
+
+        #[test]
+        fn handles_empty() {
+            let mut store = Indexed::<&str, i32>::default();
+            let checkpoint = store.snapshot();
+            store.insert("a", 1);
+            store.restore(checkpoint);
+            assert!(store.iter().next().is_none());
+        }
+    }
+}
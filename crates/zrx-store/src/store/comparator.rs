@@ -26,7 +26,7 @@
 //! Comparator.
 
 use std::cmp::Ordering;
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
 
 mod comparable;
 
@@ -59,6 +59,85 @@ pub struct Ascending;
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Descending;
 
+/// Comparator for ascending order of a key derived from a value.
+///
+/// This comparator orders values by the key that is extracted by the given
+/// function, e.g., a field of a struct, while still retaining the full value.
+/// Values with equal keys fall back to their own ordering as a tie-break, so
+/// the ordering is total, and distinct values never compare as equal, which
+/// is necessary for stores like [`Ordered`][] to retain all values.
+///
+/// [`Ordered`]: crate::store::decorator::Ordered
+///
+/// # Examples
+///
+/// ```
+/// use zrx_store::comparator::{ByKey, Comparator};
+///
+/// // Create comparator and compare values by key
+/// let comparator = ByKey::new(|value: &(u64, &str)| value.0);
+/// assert!(comparator.cmp(&(1, "b"), &(2, "a")).is_lt());
+/// ```
+#[derive(Clone)]
+pub struct ByKey<F>(F);
+
+/// Comparator that reverses the order of another comparator.
+///
+/// This comparator wraps an arbitrary comparator and inverts its result, so
+/// custom comparators can be used in descending order without implementing
+/// an inverted variant for each of them. For types implementing [`Ord`], the
+/// built-in [`Descending`] comparator is equivalent to `Reverse<Ascending>`.
+///
+/// # Examples
+///
+/// ```
+/// use zrx_store::comparator::{ByKey, Comparator, Reverse};
+///
+/// // Create comparator and compare values by key in reverse
+/// let comparator = Reverse::new(ByKey::new(|value: &(u64, &str)| value.0));
+/// assert!(comparator.cmp(&(1, "b"), &(2, "a")).is_gt());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reverse<C>(C);
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<F> ByKey<F> {
+    /// Creates a comparator for the key extracted by the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::comparator::ByKey;
+    ///
+    /// // Create comparator
+    /// let comparator = ByKey::new(|value: &(u64, &str)| value.0);
+    /// ```
+    #[inline]
+    pub fn new(f: F) -> Self {
+        Self(f)
+    }
+}
+
+impl<C> Reverse<C> {
+    /// Creates a comparator that reverses the given comparator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::comparator::{Ascending, Reverse};
+    ///
+    /// // Create comparator
+    /// let comparator = Reverse::new(Ascending);
+    /// ```
+    #[inline]
+    pub fn new(comparator: C) -> Self {
+        Self(comparator)
+    }
+}
+
 // ----------------------------------------------------------------------------
 // Trait implementations
 // ----------------------------------------------------------------------------
@@ -85,6 +164,39 @@ where
     }
 }
 
+impl<T, U, F> Comparator<T> for ByKey<F>
+where
+    T: Ord,
+    U: Ord,
+    F: Fn(&T) -> U,
+{
+    /// Compares two values by their keys in ascending order.
+    #[inline]
+    fn cmp(&self, a: &T, b: &T) -> Ordering {
+        (self.0)(a).cmp(&(self.0)(b)).then_with(|| a.cmp(b))
+    }
+}
+
+impl<T, C> Comparator<T> for Reverse<C>
+where
+    C: Comparator<T>,
+{
+    /// Compares two values in reverse order of the wrapped comparator.
+    #[inline]
+    fn cmp(&self, a: &T, b: &T) -> Ordering {
+        self.0.cmp(b, a)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<F> fmt::Debug for ByKey<F> {
+    /// Formats the comparator for debugging.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ByKey").finish_non_exhaustive()
+    }
+}
+
 // ----------------------------------------------------------------------------
 // Blanket implementations
 // ----------------------------------------------------------------------------
@@ -25,8 +25,14 @@
 
 //! Store decorators.
 
+pub mod filtered;
 pub mod indexed;
+pub mod map_values;
 pub mod ordered;
+pub mod passthrough;
 
+pub use filtered::Filtered;
 pub use indexed::Indexed;
+pub use map_values::MapValues;
 pub use ordered::Ordered;
+pub use passthrough::Passthrough;
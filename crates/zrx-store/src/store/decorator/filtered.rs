@@ -0,0 +1,358 @@
+// Copyright (c) 2025-2026 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// All contributions are certified under the DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Filtering decorator, exposing only values of a store passing a predicate.
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::store::key::Key;
+use crate::store::{Store, StoreIterable, StoreKeyValue};
+
+mod iter;
+
+pub use iter::{Iter, Keys, Values};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Filtering decorator, exposing only values of a store passing a predicate.
+///
+/// This is a read-only view over a [`Store`], which only exposes items whose
+/// values satisfy the given predicate, without copying the underlying store.
+/// Items that don't pass the predicate are invisible to all methods, i.e.,
+/// they are neither returned by [`Store::get`], nor yielded by iterators.
+///
+/// Note that [`Store::len`] must evaluate the predicate for all items of the
+/// underlying store, which is why it has a complexity of O(n). [`StoreMut`][]
+/// is deliberately not implemented, as inserted values might be invisible.
+///
+/// [`StoreMut`]: crate::store::StoreMut
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use zrx_store::decorator::Filtered;
+/// use zrx_store::{StoreIterable, StoreMut};
+///
+/// // Create store and initial state
+/// let mut store = HashMap::new();
+/// store.insert("a", 4);
+/// store.insert("b", -2);
+///
+/// // Create filtering decorator over the store
+/// let store = Filtered::new(store, |value: &i32| *value > 0);
+///
+/// // Create iterator over the store
+/// for (key, value) in store.iter() {
+///     println!("{key}: {value}");
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Filtered<K, V, S, F>
+where
+    K: Key,
+    S: Store<K, V>,
+    F: Fn(&V) -> bool,
+{
+    /// Underlying store.
+    store: S,
+    /// Predicate function.
+    f: F,
+    /// Capture types.
+    marker: PhantomData<(K, V)>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<K, V, S, F> Filtered<K, V, S, F>
+where
+    K: Key,
+    S: Store<K, V>,
+    F: Fn(&V) -> bool,
+{
+    /// Creates a filtering decorator over a store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Filtered;
+    ///
+    /// // Create filtering decorator over a store
+    /// let store = HashMap::<&str, i32>::new();
+    /// let store = Filtered::new(store, |value: &i32| *value > 0);
+    /// ```
+    #[inline]
+    pub fn new(store: S, f: F) -> Self {
+        Self { store, f, marker: PhantomData }
+    }
+
+    /// Returns the underlying store, consuming the filtering decorator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Filtered;
+    ///
+    /// // Create filtering decorator over a store
+    /// let store = HashMap::<&str, i32>::new();
+    /// let store = Filtered::new(store, |value: &i32| *value > 0);
+    ///
+    /// // Obtain underlying store
+    /// let store = store.into_inner();
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.store
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<K, V, S, F> Store<K, V> for Filtered<K, V, S, F>
+where
+    K: Key,
+    S: StoreIterable<K, V>,
+    F: Fn(&V) -> bool,
+{
+    /// Returns a reference to the value identified by the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Filtered;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("a", 4);
+    /// store.insert("b", -2);
+    ///
+    /// // Obtain reference to value
+    /// let store = Filtered::new(store, |value: &i32| *value > 0);
+    /// assert_eq!(store.get(&"a"), Some(&4));
+    /// assert_eq!(store.get(&"b"), None);
+    /// ```
+    #[inline]
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        self.store.get(key).filter(|value| (self.f)(value))
+    }
+
+    /// Returns whether the store contains the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Filtered;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("key", 42);
+    ///
+    /// // Ensure presence of key
+    /// let store = Filtered::new(store, |value: &i32| *value > 0);
+    /// let check = store.contains_key(&"key");
+    /// assert_eq!(check, true);
+    /// ```
+    #[inline]
+    fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        self.get(key).is_some()
+    }
+
+    /// Returns the number of items in the store.
+    ///
+    /// Note that this method evaluates the predicate for all items of the
+    /// underlying store, which has a complexity of O(n).
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Returns whether the store is empty.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+}
+
+impl<K, V, S, F> StoreKeyValue<K, V> for Filtered<K, V, S, F>
+where
+    K: Key,
+    S: StoreIterable<K, V> + StoreKeyValue<K, V>,
+    F: Fn(&V) -> bool,
+{
+    /// Returns references to the key and value identified by the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Filtered;
+    /// use zrx_store::{StoreKeyValue, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("key", 42);
+    ///
+    /// // Obtain references to key and value
+    /// let store = Filtered::new(store, |value: &i32| *value > 0);
+    /// let entry = store.get_key_value(&"key");
+    /// assert_eq!(entry, Some((&"key", &42)));
+    /// ```
+    #[inline]
+    fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        let opt = self.store.get_key_value(key);
+        opt.filter(|(_, value)| (self.f)(value))
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<K, V, S, F> fmt::Debug for Filtered<K, V, S, F>
+where
+    K: Key,
+    S: fmt::Debug + Store<K, V>,
+    F: Fn(&V) -> bool,
+{
+    /// Formats the filtering decorator for debugging.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Filtered")
+            .field("store", &self.store)
+            .finish_non_exhaustive()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod get {
+        use std::collections::HashMap;
+
+        use crate::decorator::Filtered;
+        use crate::Store;
+
+        #[test]
+        fn handles_predicate() {
+            let mut store = HashMap::new();
+            store.insert("a", 4);
+            store.insert("b", -2);
+            let store = Filtered::new(store, |value: &i32| *value > 0);
+            assert_eq!(store.get(&"a"), Some(&4));
+            assert_eq!(store.get(&"b"), None);
+            assert!(store.contains_key(&"a"));
+            assert!(!store.contains_key(&"b"));
+        }
+    }
+
+    mod len {
+        use std::collections::HashMap;
+
+        use crate::decorator::Filtered;
+        use crate::Store;
+
+        #[test]
+        fn handles_predicate() {
+            let mut store = HashMap::new();
+            store.insert("a", 4);
+            store.insert("b", -2);
+            store.insert("c", 3);
+            let store = Filtered::new(store, |value: &i32| *value > 0);
+            assert_eq!(store.len(), 2);
+            assert!(!store.is_empty());
+        }
+
+        #[test]
+        fn handles_empty() {
+            let mut store = HashMap::new();
+            store.insert("a", -4);
+            let store = Filtered::new(store, |value: &i32| *value > 0);
+            assert_eq!(store.len(), 0);
+            assert!(store.is_empty());
+        }
+    }
+
+    mod iter {
+        use std::collections::HashMap;
+
+        use crate::decorator::{Filtered, Indexed};
+        use crate::{StoreIterable, StoreKeys, StoreValues};
+
+        #[test]
+        fn handles_predicate() {
+            let mut store = HashMap::new();
+            store.insert("a", 4);
+            store.insert("b", -2);
+            let store = Filtered::new(store, |value: &i32| *value > 0);
+            assert_eq!(
+                store.iter().collect::<Vec<_>>(),
+                Vec::from([(&"a", &4)])
+            );
+            assert_eq!(store.keys().collect::<Vec<_>>(), Vec::from([&"a"]));
+            assert_eq!(store.values().collect::<Vec<_>>(), Vec::from([&4]));
+        }
+
+        #[test]
+        fn handles_decorator() {
+            let mut store = Indexed::default();
+            store.insert("a", 4);
+            store.insert("b", -2);
+            store.insert("c", 3);
+            store.insert("d", 1);
+            let store = Filtered::new(store, |value: &i32| *value > 1);
+            assert_eq!(
+                store.iter().collect::<Vec<_>>(),
+                Vec::from([(&"c", &3), (&"a", &4)])
+            );
+        }
+    }
+}
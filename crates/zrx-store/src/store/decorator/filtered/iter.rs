@@ -0,0 +1,245 @@
+// Copyright (c) 2025-2026 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// All contributions are certified under the DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Iterator implementations for [`Filtered`].
+
+use crate::store::key::Key;
+use crate::store::{StoreIterable, StoreKeys, StoreValues};
+
+use super::Filtered;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Iterator over the items of a [`Filtered`] store.
+pub struct Iter<'a, I, F> {
+    /// Underlying iterator.
+    iter: I,
+    /// Predicate function.
+    f: &'a F,
+}
+
+/// Iterator over the keys of a [`Filtered`] store.
+pub struct Keys<'a, I, F> {
+    /// Underlying iterator.
+    iter: I,
+    /// Predicate function.
+    f: &'a F,
+}
+
+/// Iterator over the values of a [`Filtered`] store.
+pub struct Values<'a, I, F> {
+    /// Underlying iterator.
+    iter: I,
+    /// Predicate function.
+    f: &'a F,
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<K, V, S, F> StoreIterable<K, V> for Filtered<K, V, S, F>
+where
+    K: Key,
+    S: StoreIterable<K, V>,
+    F: Fn(&V) -> bool,
+{
+    type Iter<'a> = Iter<'a, S::Iter<'a>, F>
+    where
+        Self: 'a;
+
+    /// Creates an iterator over the items of a store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Filtered;
+    /// use zrx_store::{StoreIterable, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("key", 42);
+    ///
+    /// // Create iterator over the store
+    /// let store = Filtered::new(store, |value: &i32| *value > 0);
+    /// for (key, value) in store.iter() {
+    ///     println!("{key}: {value}");
+    /// }
+    /// ```
+    #[inline]
+    fn iter(&self) -> Self::Iter<'_> {
+        Iter {
+            iter: self.store.iter(),
+            f: &self.f,
+        }
+    }
+}
+
+impl<K, V, S, F> StoreKeys<K, V> for Filtered<K, V, S, F>
+where
+    K: Key,
+    S: StoreIterable<K, V>,
+    F: Fn(&V) -> bool,
+{
+    type Keys<'a> = Keys<'a, S::Iter<'a>, F>
+    where
+        Self: 'a;
+
+    /// Creates an iterator over the keys of a store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Filtered;
+    /// use zrx_store::{StoreKeys, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("key", 42);
+    ///
+    /// // Create iterator over the store
+    /// let store = Filtered::new(store, |value: &i32| *value > 0);
+    /// for key in store.keys() {
+    ///     println!("{key}");
+    /// }
+    /// ```
+    #[inline]
+    fn keys(&self) -> Self::Keys<'_> {
+        Keys {
+            iter: self.store.iter(),
+            f: &self.f,
+        }
+    }
+}
+
+impl<K, V, S, F> StoreValues<K, V> for Filtered<K, V, S, F>
+where
+    K: Key,
+    S: StoreIterable<K, V> + StoreValues<K, V>,
+    F: Fn(&V) -> bool,
+{
+    type Values<'a> = Values<'a, S::Values<'a>, F>
+    where
+        Self: 'a;
+
+    /// Creates an iterator over the values of a store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Filtered;
+    /// use zrx_store::{StoreMut, StoreValues};
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("key", 42);
+    ///
+    /// // Create iterator over the store
+    /// let store = Filtered::new(store, |value: &i32| *value > 0);
+    /// for value in store.values() {
+    ///     println!("{value}");
+    /// }
+    /// ```
+    #[inline]
+    fn values(&self) -> Self::Values<'_> {
+        Values {
+            iter: self.store.values(),
+            f: &self.f,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<'a, K, V, I, F> Iterator for Iter<'a, I, F>
+where
+    K: 'a,
+    V: 'a,
+    I: Iterator<Item = (&'a K, &'a V)>,
+    F: Fn(&V) -> bool,
+{
+    type Item = (&'a K, &'a V);
+
+    /// Returns the next item.
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.find(|(_, value)| (self.f)(value))
+    }
+
+    /// Returns the bounds on the remaining length of the iterator.
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<'a, K, V, I, F> Iterator for Keys<'a, I, F>
+where
+    K: 'a,
+    V: 'a,
+    I: Iterator<Item = (&'a K, &'a V)>,
+    F: Fn(&V) -> bool,
+{
+    type Item = &'a K;
+
+    /// Returns the next key.
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let iter = self.iter.by_ref();
+        iter.find_map(|(key, value)| (self.f)(value).then_some(key))
+    }
+
+    /// Returns the bounds on the remaining length of the iterator.
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<'a, V, I, F> Iterator for Values<'a, I, F>
+where
+    V: 'a,
+    I: Iterator<Item = &'a V>,
+    F: Fn(&V) -> bool,
+{
+    type Item = &'a V;
+
+    /// Returns the next value.
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.find(|value| (self.f)(value))
+    }
+
+    /// Returns the bounds on the remaining length of the iterator.
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
@@ -25,7 +25,7 @@
 
 //! Indexing decorator, adding index and range access to a store.
 
-use ahash::HashMap;
+use ahash::{HashMap, HashSet};
 use std::borrow::Borrow;
 use std::cmp::Ordering;
 use std::fmt;
@@ -34,7 +34,10 @@ use std::ops::{Index, Range};
 
 use crate::store::comparator::{Ascending, Comparator};
 use crate::store::key::Key;
-use crate::store::{Store, StoreIterable, StoreMut, StoreWithComparator};
+use crate::store::{
+    Store, StoreIterable, StoreKeyValue, StoreMut, StoreStats,
+    StoreWithComparator,
+};
 
 mod into_iter;
 mod iter;
@@ -150,6 +153,97 @@ where
     }
 }
 
+impl<K, V, S, C> Indexed<K, V, S, C>
+where
+    K: Key,
+    S: Store<K, V>,
+{
+    /// Returns the first item in the ordering.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Indexed;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Indexed::default();
+    /// store.insert("a", 42);
+    /// store.insert("b", 84);
+    ///
+    /// // Obtain first item
+    /// let item = store.first();
+    /// assert_eq!(item, Some((&"a", &42)));
+    /// ```
+    #[inline]
+    pub fn first(&self) -> Option<(&K, &V)> {
+        let opt = self.ordering.first();
+        opt.and_then(|key| self.store.get(key).map(|value| (key, value)))
+    }
+
+    /// Returns the last item in the ordering.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Indexed;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Indexed::default();
+    /// store.insert("a", 42);
+    /// store.insert("b", 84);
+    ///
+    /// // Obtain last item
+    /// let item = store.last();
+    /// assert_eq!(item, Some((&"b", &84)));
+    /// ```
+    #[inline]
+    pub fn last(&self) -> Option<(&K, &V)> {
+        let opt = self.ordering.last();
+        opt.and_then(|key| self.store.get(key).map(|value| (key, value)))
+    }
+
+    /// Returns the index of the first item for which the predicate is false.
+    ///
+    /// This method performs a binary search over the ordering, which requires
+    /// the predicate to be monotone with respect to the comparator, i.e., it
+    /// must return `true` for all items up to some index, and `false` for all
+    /// items after that. Then, the returned index is the number of items for
+    /// which the predicate holds, e.g., the number of values below a bound. If
+    /// the predicate isn't monotone, the returned index is unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Indexed;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Indexed::default();
+    /// store.insert("a", 4);
+    /// store.insert("b", 2);
+    /// store.insert("c", 3);
+    /// store.insert("d", 1);
+    ///
+    /// // Obtain number of values below bound
+    /// let index = store.partition_point(|value| *value < 3);
+    /// assert_eq!(index, 2);
+    /// ```
+    #[allow(clippy::missing_panics_doc)]
+    #[inline]
+    pub fn partition_point<F>(&self, pred: F) -> usize
+    where
+        F: Fn(&V) -> bool,
+    {
+        // We can safely use expect here, since we're iterating over a store
+        // that is synchronized with the ordering
+        self.ordering.partition_point(|key| {
+            pred(self.store.get(key).expect("invariant"))
+        })
+    }
+}
+
 impl<K, V, S, C> Indexed<K, V, S, C>
 where
     K: Key,
@@ -157,6 +251,83 @@ where
     S: Store<K, V>,
     C: Comparator<V>,
 {
+    /// Returns the index of the key in the ordering.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Indexed;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Indexed::default();
+    /// store.insert("a", 42);
+    /// store.insert("b", 21);
+    ///
+    /// // Obtain index of key
+    /// let index = store.position_of(&"a");
+    /// assert_eq!(index, Some(1));
+    /// ```
+    #[inline]
+    pub fn position_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        let value = self.store.get(key)?;
+        self.position(key, value).ok()
+    }
+
+    /// Re-sorts the ordering under the given comparator, replacing it.
+    ///
+    /// This allows to change the ordering criterion of an existing store,
+    /// without reconstructing it from scratch. Since the comparator is part
+    /// of the type, the store is consumed and returned with the comparator,
+    /// which may be of any type, and the ordering is re-sorted exactly once.
+    /// Values that compare as equal are ordered by their keys, as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Indexed;
+    /// use zrx_store::{StoreIterable, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Indexed::default();
+    /// store.insert("a", 1);
+    /// store.insert("b", 2);
+    ///
+    /// // Re-sort store in descending order
+    /// let store = store.reorder(|a: &i32, b: &i32| b.cmp(a));
+    /// assert_eq!(store.first(), Some((&"b", &2)));
+    /// ```
+    #[allow(clippy::missing_panics_doc)]
+    pub fn reorder<D>(self, comparator: D) -> Indexed<K, V, S, D>
+    where
+        D: Comparator<V>,
+    {
+        let Self { store, mut ordering, .. } = self;
+
+        // We can safely use expect here, since we're iterating over a store
+        // that is synchronized with the ordering
+        ordering.sort_unstable_by(|a, b| {
+            let x = store.get(a).expect("invariant");
+            let y = store.get(b).expect("invariant");
+            match comparator.cmp(x, y) {
+                Ordering::Equal => a.cmp(b),
+                ordering => ordering,
+            }
+        });
+
+        // Return store with new comparator
+        Indexed {
+            store,
+            ordering,
+            comparator,
+            marker: PhantomData,
+        }
+    }
+
     /// Returns the position of the key-value pair in the ordering, or the
     /// position where it should be inserted if the key does not exist.
     fn position<Q>(&self, key: &Q, value: &V) -> Result<usize, usize>
@@ -322,6 +493,107 @@ where
             None
         }
     }
+
+    /// Removes the key-value pair at the given index.
+    ///
+    /// Subsequent items are shifted, so the ordering is preserved. In case the
+    /// index is out of bounds, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Indexed;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Indexed::default();
+    /// store.insert("a", 42);
+    /// store.insert("b", 84);
+    ///
+    /// // Remove and return entry
+    /// let entry = store.remove_index(0);
+    /// assert_eq!(entry, Some(("a", 42)));
+    /// ```
+    #[inline]
+    pub fn remove_index(&mut self, n: usize) -> Option<(K, V)> {
+        if n < self.ordering.len() {
+            let key = self.ordering.remove(n);
+            self.store.remove_entry(&key)
+        } else {
+            None
+        }
+    }
+}
+
+impl<K, V, S, C> Indexed<K, V, S, C>
+where
+    K: Key,
+    S: Store<K, V>,
+    C: Comparator<V>,
+{
+    /// Checks whether the ordering is consistent with the store.
+    ///
+    /// This method verifies that every key in the ordering exists in the store,
+    /// that every key of the store appears exactly once in the ordering, and
+    /// that the ordering is sorted by value under the comparator, and by key
+    /// for equal values. It's intended for testing compositions of decorators,
+    /// e.g., in property tests, and has a complexity of O(n).
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error describing the first violated invariant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Indexed;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Indexed::default();
+    /// store.insert("a", 42);
+    /// store.insert("b", 84);
+    ///
+    /// // Check invariants
+    /// assert!(store.check_invariants().is_ok());
+    /// ```
+    pub fn check_invariants(&self) -> Result<(), String> {
+        if self.ordering.len() != self.store.len() {
+            return Err(format!(
+                "ordering has {} keys, but store has {} items",
+                self.ordering.len(),
+                self.store.len()
+            ));
+        }
+
+        // Ensure all keys are unique and exist in the store, which, together
+        // with the length check, means that every key appears exactly once
+        let mut seen = HashSet::default();
+        let mut prior: Option<(&K, &V)> = None;
+        for (n, key) in self.ordering.iter().enumerate() {
+            if !seen.insert(key) {
+                return Err(format!("key at index {n} is duplicated"));
+            }
+            let Some(value) = self.store.get(key) else {
+                return Err(format!("key at index {n} is missing from store"));
+            };
+
+            // Ensure the key is ordered after its predecessor
+            if let Some((check, prior)) = prior {
+                let ordering = match self.comparator.cmp(prior, value) {
+                    Ordering::Equal => check.cmp(key),
+                    ordering => ordering,
+                };
+                if ordering != Ordering::Less {
+                    return Err(format!("key at index {n} is out of order"));
+                }
+            }
+            prior = Some((key, value));
+        }
+
+        // All invariants hold
+        Ok(())
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -390,6 +662,37 @@ where
     }
 }
 
+impl<K, V, S, C> StoreKeyValue<K, V> for Indexed<K, V, S, C>
+where
+    K: Key,
+    S: StoreKeyValue<K, V>,
+{
+    /// Returns references to the key and value identified by the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Indexed;
+    /// use zrx_store::{StoreKeyValue, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Indexed::default();
+    /// store.insert("key", 42);
+    ///
+    /// // Obtain references to key and value
+    /// let entry = store.get_key_value(&"key");
+    /// assert_eq!(entry, Some((&"key", &42)));
+    /// ```
+    #[inline]
+    fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        self.store.get_key_value(key)
+    }
+}
+
 impl<K, V, S, C> StoreMut<K, V> for Indexed<K, V, S, C>
 where
     K: Key,
@@ -514,6 +817,45 @@ where
         }
     }
 
+    /// Removes the values identified by the keys.
+    ///
+    /// Instead of shifting the ordering for each removed key, all keys are
+    /// removed from the store first, and the ordering is then updated in a
+    /// single pass, which retains the relative order of all remaining keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Indexed;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Indexed::default();
+    /// store.insert("a", 42);
+    /// store.insert("b", 84);
+    /// store.insert("c", 21);
+    ///
+    /// // Remove values
+    /// let n = store.remove_all(["a", "c", "d"]);
+    /// assert_eq!(n, 2);
+    /// assert_eq!(store.len(), 1);
+    /// ```
+    fn remove_all<I>(&mut self, keys: I) -> usize
+    where
+        I: IntoIterator<Item = K>,
+    {
+        let removed = keys
+            .into_iter()
+            .filter(|key| self.store.remove(key).is_some())
+            .collect::<HashSet<_>>();
+
+        // Update ordering in a single pass, if any keys were removed
+        if !removed.is_empty() {
+            self.ordering.retain(|key| !removed.contains(key));
+        }
+        removed.len()
+    }
+
     /// Clears the store, removing all items.
     ///
     /// # Examples
@@ -535,6 +877,58 @@ where
         self.store.clear();
         self.ordering.clear();
     }
+
+    /// Shrinks the capacity of the store as much as possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Indexed;
+    /// use zrx_store::{StoreMut, StoreStats};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Indexed::default();
+    /// store.insert("a", 42);
+    /// store.insert("b", 84);
+    /// store.remove(&"b");
+    ///
+    /// // Shrink store
+    /// store.shrink_to_fit();
+    /// ```
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        self.store.shrink_to_fit();
+        self.ordering.shrink_to_fit();
+    }
+}
+
+impl<K, V, S, C> StoreStats<K, V> for Indexed<K, V, S, C>
+where
+    K: Key,
+    S: StoreStats<K, V>,
+{
+    /// Returns the number of items the store can hold without reallocating.
+    ///
+    /// This method returns the smaller of the capacities of the underlying
+    /// store and the ordering, as both must grow when items are inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Indexed;
+    /// use zrx_store::{StoreMut, StoreStats};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Indexed::default();
+    /// store.insert("key", 42);
+    ///
+    /// // Obtain capacity of store
+    /// assert!(store.capacity() >= 1);
+    /// ```
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.store.capacity().min(self.ordering.capacity())
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -663,6 +1057,39 @@ where
     }
 }
 
+impl<K, V, S, C> Extend<(K, V)> for Indexed<K, V, S, C>
+where
+    K: Key,
+    V: Ord,
+    S: StoreMut<K, V>,
+    C: Comparator<V>,
+{
+    /// Extends the store with the items of an iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Indexed;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Indexed::default();
+    /// store.insert("a", 4);
+    ///
+    /// // Extend store with items
+    /// store.extend([("b", 2), ("c", 3)]);
+    /// ```
+    #[inline]
+    fn extend<T>(&mut self, iter: T)
+    where
+        T: IntoIterator<Item = (K, V)>,
+    {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
 #[allow(clippy::into_iter_without_iter)]
 impl<'a, K, V, S, C> IntoIterator for &'a Indexed<K, V, S, C>
 where
@@ -741,6 +1168,381 @@ where
         f.debug_struct("Indexed")
             .field("store", &self.store)
             .field("ordering", &self.ordering)
+            .field("comparator", &self.comparator)
             .finish_non_exhaustive()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod clone {
+        use std::collections::HashMap;
+
+        use crate::decorator::Indexed;
+        use crate::{StoreIterable, StoreWithComparator};
+
+        #[test]
+        fn handles_comparator() {
+            let mut store: Indexed<_, _, HashMap<_, _>, _> =
+                Indexed::with_comparator(|a: &i32, b: &i32| b.cmp(a));
+            store.insert("a", 1);
+            store.insert("b", 3);
+            let mut clone = store.clone();
+            store.insert("c", 2);
+            clone.insert("c", 2);
+            clone.insert("d", 4);
+            store.insert("d", 4);
+            assert_eq!(
+                store.iter().collect::<Vec<_>>(),
+                clone.iter().collect::<Vec<_>>()
+            );
+            assert_eq!(
+                store.iter().collect::<Vec<_>>(), // fmt
+                Vec::from([(&"d", &4), (&"b", &3), (&"c", &2), (&"a", &1)])
+            );
+        }
+    }
+
+    mod first {
+        use crate::decorator::Indexed;
+
+        #[test]
+        fn handles_items() {
+            let mut store = Indexed::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            store.insert("d", 1);
+            assert_eq!(store.first(), Some((&"d", &1)));
+            assert_eq!(store[0], "d");
+        }
+
+        #[test]
+        fn handles_empty() {
+            let store = Indexed::<&str, i32>::default();
+            assert_eq!(store.first(), None);
+        }
+    }
+
+    mod last {
+        use crate::decorator::Indexed;
+        use crate::Store;
+
+        #[test]
+        fn handles_items() {
+            let mut store = Indexed::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            store.insert("d", 1);
+            assert_eq!(store.last(), Some((&"a", &4)));
+            assert_eq!(store[store.len() - 1], "a");
+        }
+
+        #[test]
+        fn handles_empty() {
+            let store = Indexed::<&str, i32>::default();
+            assert_eq!(store.last(), None);
+        }
+    }
+
+    mod partition_point {
+        use crate::decorator::Indexed;
+
+        #[test]
+        fn handles_bound() {
+            let mut store = Indexed::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            store.insert("d", 1);
+            store.insert("e", 3);
+            assert_eq!(store.partition_point(|value| *value < 3), 2);
+            assert_eq!(store.partition_point(|value| *value <= 3), 4);
+        }
+
+        #[test]
+        fn handles_extremes() {
+            let mut store = Indexed::default();
+            store.insert("a", 1);
+            store.insert("b", 2);
+            assert_eq!(store.partition_point(|value| *value < 0), 0);
+            assert_eq!(store.partition_point(|value| *value < 9), 2);
+        }
+
+        #[test]
+        fn handles_empty() {
+            let store = Indexed::<&str, i32>::default();
+            assert_eq!(store.partition_point(|value| *value < 3), 0);
+        }
+    }
+
+    mod position_of {
+        use crate::decorator::Indexed;
+
+        #[test]
+        fn handles_keys() {
+            let mut store = Indexed::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            store.insert("d", 1);
+            for key in ["a", "b", "c", "d"] {
+                let index = store.position_of(&key).unwrap();
+                assert_eq!(&store[index], &key);
+            }
+        }
+
+        #[test]
+        fn handles_missing() {
+            let mut store = Indexed::default();
+            store.insert("a", 4);
+            assert_eq!(store.position_of(&"b"), None);
+        }
+    }
+
+    mod reorder {
+        use std::cmp::Ordering;
+
+        use crate::comparator::Descending;
+        use crate::decorator::Indexed;
+        use crate::StoreIterable;
+
+        #[test]
+        fn handles_descending() {
+            let mut store = Indexed::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            store.insert("d", 1);
+            let store = store.reorder(Descending);
+            assert_eq!(
+                store.iter().collect::<Vec<_>>(),
+                Vec::from([(&"a", &4), (&"c", &3), (&"b", &2), (&"d", &1)])
+            );
+            assert_eq!(store.check_invariants(), Ok(()));
+        }
+
+        #[test]
+        fn handles_closure() {
+            let mut store = Indexed::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            let store = store.reorder(|a: &i32, b: &i32| (a % 2).cmp(&(b % 2)));
+            assert_eq!(
+                store.iter().collect::<Vec<_>>(),
+                Vec::from([(&"a", &4), (&"b", &2), (&"c", &3)])
+            );
+            assert_eq!(store.check_invariants(), Ok(()));
+        }
+
+        #[test]
+        fn handles_equal() {
+            let mut store = Indexed::default();
+            store.insert("b", 1);
+            store.insert("a", 2);
+            store.insert("c", 3);
+            let store = store.reorder(|_: &i32, _: &i32| Ordering::Equal);
+            assert_eq!(
+                store.iter().collect::<Vec<_>>(),
+                Vec::from([(&"a", &2), (&"b", &1), (&"c", &3)])
+            );
+            assert_eq!(store.check_invariants(), Ok(()));
+        }
+    }
+
+    mod remove_index {
+        use crate::decorator::Indexed;
+        use crate::Store;
+
+        #[test]
+        fn handles_index() {
+            let mut store = Indexed::default();
+            store.insert("a", 1);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            store.insert("d", 4);
+            assert_eq!(store.remove_index(1), Some(("b", 2)));
+            assert_eq!(store.len(), 3);
+            assert_eq!(
+                (store[0], store[1], store[2]), // fmt
+                ("a", "c", "d")
+            );
+        }
+
+        #[test]
+        fn handles_out_of_bounds() {
+            let mut store = Indexed::default();
+            store.insert("a", 1);
+            assert_eq!(store.remove_index(1), None);
+            assert_eq!(store.len(), 1);
+        }
+    }
+
+    mod check_invariants {
+        use crate::decorator::Indexed;
+
+        #[test]
+        fn handles_valid() {
+            let mut store = Indexed::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 2);
+            store.remove(&"a");
+            store.insert("d", 1);
+            assert_eq!(store.check_invariants(), Ok(()));
+        }
+
+        #[test]
+        fn handles_invalid() {
+            let mut store = Indexed::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.ordering.swap(0, 1);
+            assert!(store.check_invariants().is_err());
+            store.ordering.push("c");
+            assert!(store.check_invariants().is_err());
+        }
+    }
+
+    mod get_key_value {
+        use crate::decorator::Indexed;
+        use crate::StoreKeyValue;
+
+        #[test]
+        fn handles_stored_key() {
+            let mut store = Indexed::default();
+            let key = String::from("b");
+            let ptr = key.as_ptr();
+            store.insert(String::from("a"), 1);
+            store.insert(key, 2);
+            let (key, value) = store.get_key_value(&String::from("b")).unwrap();
+            assert_eq!(key.as_ptr(), ptr);
+            assert_eq!(value, &2);
+        }
+
+        #[test]
+        fn handles_missing() {
+            let mut store = Indexed::default();
+            store.insert(String::from("a"), 1);
+            assert_eq!(store.get_key_value(&String::from("b")), None);
+        }
+    }
+
+    mod remove_all {
+        use crate::decorator::Indexed;
+        use crate::{StoreIterable, StoreMut};
+
+        #[test]
+        fn handles_subset() {
+            let mut store = Indexed::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            store.insert("d", 1);
+            assert_eq!(store.remove_all(["b", "d", "e", "b"]), 2);
+            assert_eq!(
+                store.iter().collect::<Vec<_>>(),
+                Vec::from([(&"c", &3), (&"a", &4)])
+            );
+            assert_eq!(store.check_invariants(), Ok(()));
+        }
+    }
+
+    mod capacity {
+        use crate::decorator::Indexed;
+        use crate::{Store, StoreMut, StoreStats};
+
+        #[test]
+        fn handles_removal() {
+            let mut store = Indexed::default();
+            for n in 0..100 {
+                store.insert(n, n);
+            }
+            for n in 0..90 {
+                store.remove(&n);
+            }
+            assert_eq!(store.len(), 10);
+            assert!(store.capacity() > store.len());
+            store.shrink_to_fit();
+            assert!(store.capacity() >= store.len());
+            assert!(store.capacity() < 100);
+        }
+    }
+
+    mod shrink_to_fit {
+        use crate::decorator::Indexed;
+        use crate::{Store, StoreMut, StoreStats};
+
+        #[test]
+        fn handles_removal() {
+            let mut store = Indexed::default();
+            for n in 0..100 {
+                store.insert(n, n);
+            }
+            for n in 0..90 {
+                store.remove(&n);
+            }
+            let capacity = store.capacity();
+            store.shrink_to_fit();
+            assert!(store.capacity() < capacity);
+            assert_eq!(store.len(), 10);
+            assert_eq!((store[0], store[9]), (90, 99));
+        }
+    }
+
+    mod to_vec {
+        use crate::decorator::Indexed;
+        use crate::{Store, StoreSnapshot};
+
+        #[test]
+        fn handles_ordering() {
+            let mut store = Indexed::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            store.insert("d", 1);
+            assert_eq!(
+                store.to_vec(),
+                Vec::from([("d", 1), ("b", 2), ("c", 3), ("a", 4)])
+            );
+        }
+
+        #[test]
+        fn handles_original() {
+            let mut store = Indexed::default();
+            store.insert("a", 2);
+            store.insert("b", 1);
+            let items = store.to_vec();
+            assert_eq!(items, Vec::from([("b", 1), ("a", 2)]));
+            assert_eq!(store.len(), 2);
+            assert_eq!(store.get(&"a"), Some(&2));
+            assert_eq!(store.first(), Some((&"b", &1)));
+        }
+    }
+
+    mod extend {
+        use crate::decorator::Indexed;
+        use crate::{Store, StoreIterable};
+
+        #[test]
+        fn handles_items() {
+            let mut store = Indexed::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.extend([("c", 3), ("d", 1), ("a", 5)]);
+            assert_eq!(store.len(), 4);
+            assert_eq!(store.get(&"a"), Some(&5));
+            assert_eq!(
+                store.iter().collect::<Vec<_>>(),
+                vec![(&"d", &1), (&"b", &2), (&"c", &3), (&"a", &5)]
+            );
+        }
+    }
+}
@@ -30,8 +30,9 @@ use std::marker::PhantomData;
 use std::ops::{Bound, RangeBounds};
 use std::slice;
 
+use crate::store::comparator::Comparator;
 use crate::store::key::Key;
-use crate::store::{Store, StoreIterable, StoreKeys, StoreValues};
+use crate::store::{Store, StoreIterable, StoreKeys, StoreSeek, StoreValues};
 
 use super::Indexed;
 
@@ -126,6 +127,29 @@ where
             marker: PhantomData,
         }
     }
+
+    /// Creates an iterator over the items of a store in reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Indexed;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Indexed::default();
+    /// store.insert("a", 42);
+    /// store.insert("b", 84);
+    ///
+    /// // Create iterator over the store
+    /// for (key, value) in store.iter_rev() {
+    ///     println!("{key}: {value}");
+    /// }
+    /// ```
+    #[inline]
+    pub fn iter_rev(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.iter().rev()
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -236,6 +260,53 @@ where
     }
 }
 
+impl<K, V, S, C> StoreSeek<K, V> for Indexed<K, V, S, C>
+where
+    K: Key,
+    V: Ord,
+    S: Store<K, V>,
+    C: Comparator<V>,
+{
+    /// Creates an iterator over the items of a store, starting at the key.
+    ///
+    /// If the key is present, iteration starts at its position (inclusive),
+    /// which is found via binary search. Otherwise, the iterator is empty, as
+    /// an absent key has no value that could be used to determine its position
+    /// in the ordering, which is sorted by value, not by key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Indexed;
+    /// use zrx_store::{StoreMut, StoreSeek};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Indexed::default();
+    /// store.insert("a", 42);
+    /// store.insert("b", 84);
+    ///
+    /// // Create iterator over the store
+    /// for (key, value) in store.iter_from(&"b") {
+    ///     println!("{key}: {value}");
+    /// }
+    /// ```
+    #[allow(clippy::missing_panics_doc)]
+    #[inline]
+    fn iter_from<'a>(&'a self, key: &K) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        // We can safely use expect here, since the key is present in the
+        // store, which is synchronized with the ordering
+        let n = match self.store.get(key) {
+            Some(value) => self.position(key, value).expect("invariant"),
+            None => self.ordering.len(),
+        };
+        self.range(n..)
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 impl<'a, K, V, S> Iterator for Iter<'a, K, V, S>
@@ -260,6 +331,20 @@ where
     }
 }
 
+impl<'a, K, V, S> DoubleEndedIterator for Iter<'a, K, V, S>
+where
+    K: Key,
+    V: 'a,
+    S: Store<K, V>,
+{
+    /// Returns the next item from the back.
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let opt = self.ordering.next_back();
+        opt.and_then(|key| self.store.get(key).map(|value| (key, value)))
+    }
+}
+
 impl<'a, K, V, S> ExactSizeIterator for Iter<'a, K, V, S>
 where
     K: Key,
@@ -316,3 +401,92 @@ where
 
 /// Iterator over the keys of an [`Indexed`] store.
 pub type Keys<'a, K> = slice::Iter<'a, K>;
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod iter_from {
+        use crate::decorator::Indexed;
+        use crate::StoreSeek;
+
+        #[test]
+        fn handles_present() {
+            let mut store = Indexed::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            store.insert("d", 1);
+            assert_eq!(
+                store.iter_from(&"b").collect::<Vec<_>>(),
+                vec![(&"b", &2), (&"c", &3), (&"a", &4)]
+            );
+        }
+
+        #[test]
+        fn handles_present_first() {
+            let mut store = Indexed::default();
+            store.insert("a", 1);
+            store.insert("b", 2);
+            assert_eq!(
+                store.iter_from(&"a").collect::<Vec<_>>(),
+                vec![(&"a", &1), (&"b", &2)]
+            );
+        }
+
+        #[test]
+        fn handles_duplicate_values() {
+            let mut store = Indexed::default();
+            store.insert("a", 1);
+            store.insert("b", 1);
+            store.insert("c", 1);
+            assert_eq!(
+                store.iter_from(&"b").collect::<Vec<_>>(),
+                vec![(&"b", &1), (&"c", &1)]
+            );
+        }
+
+        #[test]
+        fn handles_absent() {
+            let mut store = Indexed::default();
+            store.insert("a", 3);
+            store.insert("b", 1);
+            store.insert("c", 2);
+            assert_eq!(store.iter_from(&"bb").count(), 0);
+        }
+
+        #[test]
+        fn handles_absent_last() {
+            let mut store = Indexed::default();
+            store.insert("a", 1);
+            store.insert("c", 3);
+            assert_eq!(store.iter_from(&"d").count(), 0);
+        }
+    }
+
+    mod iter_rev {
+        use crate::decorator::Indexed;
+
+        #[test]
+        fn handles_items() {
+            let mut store = Indexed::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            store.insert("d", 1);
+            assert_eq!(
+                store.iter_rev().collect::<Vec<_>>(),
+                vec![(&"a", &4), (&"c", &3), (&"b", &2), (&"d", &1)]
+            );
+        }
+
+        #[test]
+        fn handles_empty() {
+            let store = Indexed::<&str, i32>::default();
+            assert_eq!(store.iter_rev().count(), 0);
+        }
+    }
+}
@@ -0,0 +1,311 @@
+// Copyright (c) 2025-2026 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// All contributions are certified under the DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Mapping decorator, mapping values of a store on read.
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::store::key::Key;
+use crate::store::{Store, StoreIterable, StoreValues};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Mapping decorator, mapping values of a store on read.
+///
+/// This is a read-only wrapper around a [`Store`], which applies a function to
+/// values when they are read, so a store of raw values can be accessed as a
+/// store of derived values without materializing a second store.
+///
+/// Since [`Store::get`] must return references, and mapped values are created
+/// on demand, this decorator doesn't implement the store traits, as they could
+/// only expose unmapped values. Instead, it provides [`MapValues::get_mapped`],
+/// [`MapValues::iter_mapped`] and [`MapValues::values_mapped`], which return
+/// owned mapped values. Use [`MapValues::into_inner`] to access the raw values.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use zrx_store::decorator::MapValues;
+/// use zrx_store::StoreMut;
+///
+/// // Create store and initial state
+/// let mut store = HashMap::new();
+/// store.insert("a", 4);
+/// store.insert("b", 2);
+///
+/// // Create mapping decorator over the store
+/// let store = MapValues::new(store, |value: &i32| value.to_string());
+///
+/// // Create iterator over the store
+/// for (key, value) in store.iter_mapped() {
+///     println!("{key}: {value}");
+/// }
+/// ```
+#[derive(Clone)]
+pub struct MapValues<K, V, W, S, F>
+where
+    K: Key,
+    S: Store<K, V>,
+    F: Fn(&V) -> W,
+{
+    /// Underlying store.
+    store: S,
+    /// Mapping function.
+    f: F,
+    /// Capture types.
+    marker: PhantomData<(K, V, W)>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<K, V, W, S, F> MapValues<K, V, W, S, F>
+where
+    K: Key,
+    S: Store<K, V>,
+    F: Fn(&V) -> W,
+{
+    /// Creates a mapping decorator over a store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::MapValues;
+    ///
+    /// // Create mapping decorator over a store
+    /// let store = HashMap::<&str, i32>::new();
+    /// let store = MapValues::new(store, |value: &i32| value.to_string());
+    /// ```
+    #[inline]
+    pub fn new(store: S, f: F) -> Self {
+        Self { store, f, marker: PhantomData }
+    }
+
+    /// Returns the mapped value identified by the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::MapValues;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("key", 42);
+    ///
+    /// // Obtain mapped value
+    /// let store = MapValues::new(store, |value: &i32| value.to_string());
+    /// let value = store.get_mapped(&"key");
+    /// assert_eq!(value, Some(String::from("42")));
+    /// ```
+    #[inline]
+    pub fn get_mapped<Q>(&self, key: &Q) -> Option<W>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        self.store.get(key).map(&self.f)
+    }
+
+    /// Returns the underlying store, consuming the mapping decorator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::MapValues;
+    ///
+    /// // Create mapping decorator over a store
+    /// let store = HashMap::<&str, i32>::new();
+    /// let store = MapValues::new(store, |value: &i32| value.to_string());
+    ///
+    /// // Obtain underlying store
+    /// let store = store.into_inner();
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.store
+    }
+}
+
+impl<K, V, W, S, F> MapValues<K, V, W, S, F>
+where
+    K: Key,
+    S: StoreIterable<K, V>,
+    F: Fn(&V) -> W,
+{
+    /// Creates an iterator over the items of a store with mapped values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::MapValues;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("key", 42);
+    ///
+    /// // Create iterator over the store
+    /// let store = MapValues::new(store, |value: &i32| value.to_string());
+    /// for (key, value) in store.iter_mapped() {
+    ///     println!("{key}: {value}");
+    /// }
+    /// ```
+    #[inline]
+    pub fn iter_mapped(&self) -> impl Iterator<Item = (&K, W)> {
+        self.store.iter().map(|(key, value)| (key, (self.f)(value)))
+    }
+}
+
+impl<K, V, W, S, F> MapValues<K, V, W, S, F>
+where
+    K: Key,
+    S: StoreValues<K, V>,
+    F: Fn(&V) -> W,
+{
+    /// Creates an iterator over the mapped values of a store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::MapValues;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("key", 42);
+    ///
+    /// // Create iterator over the store
+    /// let store = MapValues::new(store, |value: &i32| value.to_string());
+    /// for value in store.values_mapped() {
+    ///     println!("{value}");
+    /// }
+    /// ```
+    #[inline]
+    pub fn values_mapped(&self) -> impl Iterator<Item = W> {
+        self.store.values().map(&self.f)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<K, V, W, S, F> fmt::Debug for MapValues<K, V, W, S, F>
+where
+    K: Key,
+    S: fmt::Debug + Store<K, V>,
+    F: Fn(&V) -> W,
+{
+    /// Formats the mapping decorator for debugging.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapValues")
+            .field("store", &self.store)
+            .finish_non_exhaustive()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod get_mapped {
+        use std::collections::BTreeMap;
+
+        use crate::decorator::MapValues;
+
+        #[test]
+        fn handles_present() {
+            let mut store = BTreeMap::new();
+            store.insert("a", 4);
+            let store = MapValues::new(store, |value: &i32| value.to_string());
+            assert_eq!(store.get_mapped(&"a"), Some(String::from("4")));
+        }
+
+        #[test]
+        fn handles_absent() {
+            let store = BTreeMap::<&str, i32>::new();
+            let store = MapValues::new(store, |value: &i32| value.to_string());
+            assert_eq!(store.get_mapped(&"a"), None);
+        }
+    }
+
+    mod iter_mapped {
+        use std::collections::BTreeMap;
+
+        use crate::decorator::MapValues;
+
+        #[test]
+        fn handles_values() {
+            let mut store = BTreeMap::new();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            let store = MapValues::new(store, |value: &i32| value.to_string());
+            assert_eq!(
+                store.iter_mapped().collect::<Vec<_>>(),
+                vec![(&"a", String::from("4")), (&"b", String::from("2"))]
+            );
+        }
+
+        #[test]
+        fn handles_empty() {
+            let store = BTreeMap::<&str, i32>::new();
+            let store = MapValues::new(store, |value: &i32| value.to_string());
+            assert_eq!(store.iter_mapped().count(), 0);
+        }
+    }
+
+    mod values_mapped {
+        use std::collections::BTreeMap;
+
+        use crate::decorator::MapValues;
+
+        #[test]
+        fn handles_values() {
+            let mut store = BTreeMap::new();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            let store = MapValues::new(store, |value: &i32| value.to_string());
+            assert_eq!(
+                store.values_mapped().collect::<Vec<_>>(),
+                vec![String::from("4"), String::from("2")]
+            );
+        }
+    }
+}
@@ -25,14 +25,18 @@
 
 //! Ordering decorator, adding ordering to a store.
 
-use ahash::HashMap;
+use ahash::{HashMap, HashSet};
 use std::borrow::Borrow;
+use std::cmp::Ordering;
 use std::collections::BTreeMap;
-use std::fmt;
+use std::{fmt, mem};
 
 use crate::store::comparator::{Ascending, Comparable, Comparator};
 use crate::store::key::Key;
-use crate::store::{Store, StoreIterable, StoreMut, StoreWithComparator};
+use crate::store::{
+    Store, StoreIterable, StoreKeyValue, StoreMut, StoreMutRef, StoreStats,
+    StoreWithComparator,
+};
 
 mod into_iter;
 mod iter;
@@ -127,6 +131,260 @@ where
     }
 }
 
+impl<K, V, S, C> Ordered<K, V, S, C>
+where
+    K: Key,
+    S: Store<K, V>,
+{
+    /// Returns the item with the minimum value under the comparator.
+    ///
+    /// If multiple keys share the minimum value, the first key that was added
+    /// with this value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Ordered;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Ordered::default();
+    /// store.insert("a", 42);
+    /// store.insert("b", 84);
+    ///
+    /// // Obtain item with minimum value
+    /// let item = store.min();
+    /// assert_eq!(item, Some((&"a", &42)));
+    /// ```
+    #[inline]
+    pub fn min(&self) -> Option<(&K, &V)> {
+        let opt = self.ordering.iter().next();
+        opt.and_then(|(value, keys)| keys.first().map(|key| (key, &**value)))
+    }
+
+    /// Returns the item with the maximum value under the comparator.
+    ///
+    /// If multiple keys share the maximum value, the first key that was added
+    /// with this value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Ordered;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Ordered::default();
+    /// store.insert("a", 42);
+    /// store.insert("b", 84);
+    ///
+    /// // Obtain item with maximum value
+    /// let item = store.max();
+    /// assert_eq!(item, Some((&"b", &84)));
+    /// ```
+    #[inline]
+    pub fn max(&self) -> Option<(&K, &V)> {
+        let opt = self.ordering.iter().next_back();
+        opt.and_then(|(value, keys)| keys.first().map(|key| (key, &**value)))
+    }
+
+    /// Returns the rank of the key in the ordering.
+    ///
+    /// The rank is the 0-based position of the key when iterating the store,
+    /// which is computed by counting all keys with values that are ordered
+    /// before the value of the key, as well as the keys with the same value
+    /// that were added before it. Note that this has a complexity of O(rank).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Ordered;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Ordered::default();
+    /// store.insert("a", 84);
+    /// store.insert("b", 42);
+    ///
+    /// // Obtain rank of key
+    /// let rank = store.rank_of(&"a");
+    /// assert_eq!(rank, Some(1));
+    /// ```
+    pub fn rank_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+        C: Comparator<V>,
+    {
+        let value = self.store.get(key)?;
+
+        // Walk the ordering up to the value of the key, counting all keys that
+        // are ordered before it, and then locate the key among equal values
+        let mut rank = 0;
+        for (check, keys) in &self.ordering {
+            match self.comparator.cmp(check, value) {
+                Ordering::Less => rank += keys.len(),
+                Ordering::Equal => {
+                    let opt =
+                        keys.iter().position(|check| check.borrow() == key);
+                    return opt.map(|n| rank + n);
+                }
+                Ordering::Greater => break,
+            }
+        }
+
+        // Key is not part of the ordering
+        None
+    }
+}
+
+impl<K, V, S, C> Ordered<K, V, S, C>
+where
+    K: Key,
+    V: Clone + Ord,
+    S: StoreMut<K, V>,
+    C: Comparator<V> + Clone,
+{
+    /// Removes and returns the item with the minimum value.
+    ///
+    /// The minimum value is determined by the comparator, which allows to use
+    /// the store as an addressable priority queue, as both, the ordering and
+    /// the underlying store, are kept in sync. If multiple keys share the
+    /// minimum value, the key that was added first is removed first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Ordered;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Ordered::default();
+    /// store.insert("a", 84);
+    /// store.insert("b", 42);
+    ///
+    /// // Remove and return items in order
+    /// assert_eq!(store.pop(), Some(("b", 42)));
+    /// assert_eq!(store.pop(), Some(("a", 84)));
+    /// assert_eq!(store.pop(), None);
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        let key = self.min().map(|(key, _)| key.clone())?;
+        self.remove_entry(&key)
+    }
+}
+
+impl<K, V, S, C> Ordered<K, V, S, C>
+where
+    K: Key,
+    V: Clone + Ord,
+    S: StoreMutRef<K, V>,
+    C: Comparator<V> + Clone,
+{
+    /// Updates the value identified by the key, if it exists.
+    ///
+    /// This method applies the given function to a clone of the current value,
+    /// and only writes it back if it changed, repositioning the key once in the
+    /// ordering. In contrast to [`Store::get`] followed by [`StoreMut::insert`],
+    /// this avoids a second lookup, and leaves unchanged keys in place, so they
+    /// retain their relative order among keys with equal values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Ordered;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Ordered::default();
+    /// store.insert("a", 1);
+    /// store.insert("b", 2);
+    ///
+    /// // Update value and reposition key
+    /// assert!(store.update(&"a", |value| *value = 3));
+    /// assert_eq!(store.min(), Some((&"b", &2)));
+    /// ```
+    pub fn update<F>(&mut self, key: &K, f: F) -> bool
+    where
+        F: FnOnce(&mut V),
+    {
+        let Some(value) = self.store.get_mut(key) else {
+            return false;
+        };
+
+        // Apply the function to a clone of the value, and only write it back,
+        // if it changed, so the ordering is only touched when necessary
+        let mut next = value.clone();
+        f(&mut next);
+        if next != *value {
+            let prior = mem::replace(value, next.clone());
+            self.remove_ordering(prior, key);
+            self.update_ordering(next, key.clone());
+        }
+
+        // Entry existed
+        true
+    }
+
+    /// Mutates all values in order, and then rebuilds the ordering.
+    ///
+    /// Mutable iteration is deliberately not supported, as changing values in
+    /// place would break the ordering. This method invokes the given function
+    /// on a copy of every value in the current order, and only writes back the
+    /// values and rebuilds the ordering after all values were visited, so the
+    /// store is left unchanged if the function panics. Keys with equal values
+    /// after mutation retain their relative order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Ordered;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Ordered::default();
+    /// store.insert("a", 1);
+    /// store.insert("b", 2);
+    ///
+    /// // Mutate values and rebuild ordering
+    /// store.iter_mut_then_resort(|_, value| *value = -*value);
+    /// assert_eq!(store.min(), Some((&"b", &-2)));
+    /// ```
+    #[allow(clippy::missing_panics_doc)]
+    pub fn iter_mut_then_resort<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V),
+    {
+        let mut items = Vec::with_capacity(self.store.len());
+        for key in self.ordering.values().flatten() {
+            // We can safely use expect here, since we're iterating over keys
+            // that are synchronized with the underlying store
+            let mut value = self.store.get(key).expect("invariant").clone();
+            f(key, &mut value);
+            items.push((key.clone(), value));
+        }
+
+        // All values were visited, so we can now write back the values, and
+        // rebuild the ordering from them in the order they were visited
+        let mut ordering = BTreeMap::new();
+        for (key, value) in items {
+            let comparable =
+                Comparable::new(value.clone(), self.comparator.clone());
+
+            // We can safely use expect here, as the key was visited before
+            *self.store.get_mut(&key).expect("invariant") = value;
+            ordering
+                .entry(comparable)
+                .or_insert_with(|| Vec::with_capacity(1))
+                .push(key);
+        }
+
+        // Replace ordering
+        self.ordering = ordering;
+    }
+}
+
 impl<K, V, S, C> Ordered<K, V, S, C>
 where
     K: Key,
@@ -171,6 +429,80 @@ where
     }
 }
 
+impl<K, V, S, C> Ordered<K, V, S, C>
+where
+    K: Key,
+    V: Eq,
+    S: Store<K, V>,
+    C: Comparator<V>,
+{
+    /// Checks whether the ordering is consistent with the store.
+    ///
+    /// This method verifies that every key in the ordering exists in the store,
+    /// that every key of the store appears exactly once in the ordering, and
+    /// that each key is associated with the value that is stored for it, so
+    /// the ordering is sorted consistently with the stored values. It's meant
+    /// for testing compositions of decorators, e.g., in property tests, and
+    /// has a complexity of O(n).
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error describing the first violated invariant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Ordered;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Ordered::default();
+    /// store.insert("a", 42);
+    /// store.insert("b", 84);
+    ///
+    /// // Check invariants
+    /// assert!(store.check_invariants().is_ok());
+    /// ```
+    pub fn check_invariants(&self) -> Result<(), String> {
+        let mut seen = HashSet::default();
+        for (n, (value, keys)) in self.ordering.iter().enumerate() {
+            if keys.is_empty() {
+                return Err(format!("value at index {n} has no keys"));
+            }
+
+            // Ensure all keys are unique, and exist in the store with exactly
+            // the value they're associated with in the ordering
+            for key in keys {
+                if !seen.insert(key) {
+                    return Err(format!("key of value {n} is duplicated"));
+                }
+                match self.store.get(key) {
+                    Some(check) if check == &**value => {}
+                    Some(_) => {
+                        return Err(format!("key of value {n} is out of sync"));
+                    }
+                    None => {
+                        return Err(format!("key of value {n} is missing"));
+                    }
+                }
+            }
+        }
+
+        // Ensure every key of the store appears in the ordering, which, given
+        // all keys of the ordering are unique and exist, is a length check
+        if seen.len() != self.store.len() {
+            return Err(format!(
+                "ordering has {} keys, but store has {} items",
+                seen.len(),
+                self.store.len()
+            ));
+        }
+
+        // All invariants hold
+        Ok(())
+    }
+}
+
 // ----------------------------------------------------------------------------
 // Trait implementations
 // ----------------------------------------------------------------------------
@@ -237,6 +569,37 @@ where
     }
 }
 
+impl<K, V, S, C> StoreKeyValue<K, V> for Ordered<K, V, S, C>
+where
+    K: Key,
+    S: StoreKeyValue<K, V>,
+{
+    /// Returns references to the key and value identified by the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Ordered;
+    /// use zrx_store::{StoreKeyValue, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Ordered::default();
+    /// store.insert("key", 42);
+    ///
+    /// // Obtain references to key and value
+    /// let entry = store.get_key_value(&"key");
+    /// assert_eq!(entry, Some((&"key", &42)));
+    /// ```
+    #[inline]
+    fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        self.store.get_key_value(key)
+    }
+}
+
 impl<K, V, S, C> StoreMut<K, V> for Ordered<K, V, S, C>
 where
     K: Key,
@@ -348,6 +711,63 @@ where
         self.store.clear();
         self.ordering.clear();
     }
+
+    /// Shrinks the capacity of the store as much as possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Ordered;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Ordered::default();
+    /// store.insert("a", 42);
+    /// store.insert("b", 42);
+    /// store.remove(&"b");
+    ///
+    /// // Shrink store
+    /// store.shrink_to_fit();
+    /// ```
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        self.store.shrink_to_fit();
+        for keys in self.ordering.values_mut() {
+            keys.shrink_to_fit();
+        }
+    }
+}
+
+impl<K, V, S, C> StoreStats<K, V> for Ordered<K, V, S, C>
+where
+    K: Key,
+    S: StoreStats<K, V>,
+{
+    /// Returns the number of items the store can hold without reallocating.
+    ///
+    /// This method returns the capacity of the underlying store. The ordering
+    /// is deliberately excluded, as it's a [`BTreeMap`], which allocates nodes
+    /// on demand and has no notion of spare capacity, and the vectors of keys
+    /// it holds are grouped by value, so their capacities don't relate to the
+    /// number of items the store can hold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Ordered;
+    /// use zrx_store::{StoreMut, StoreStats};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Ordered::default();
+    /// store.insert("key", 42);
+    ///
+    /// // Obtain capacity of store
+    /// assert!(store.capacity() >= 1);
+    /// ```
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.store.capacity()
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -431,6 +851,39 @@ where
     }
 }
 
+impl<K, V, S, C> Extend<(K, V)> for Ordered<K, V, S, C>
+where
+    K: Key,
+    V: Clone + Ord,
+    S: StoreMut<K, V>,
+    C: Comparator<V> + Clone,
+{
+    /// Extends the store with the items of an iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Ordered;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Ordered::default();
+    /// store.insert("a", 4);
+    ///
+    /// // Extend store with items
+    /// store.extend([("b", 2), ("c", 3)]);
+    /// ```
+    #[inline]
+    fn extend<T>(&mut self, iter: T)
+    where
+        T: IntoIterator<Item = (K, V)>,
+    {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
 #[allow(clippy::into_iter_without_iter)]
 impl<'a, K, V, S, C> IntoIterator for &'a Ordered<K, V, S, C>
 where
@@ -512,3 +965,386 @@ where
             .finish_non_exhaustive()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod min {
+        use std::collections::HashMap;
+
+        use crate::comparator::Descending;
+        use crate::decorator::Ordered;
+        use crate::{StoreMut, StoreWithComparator};
+
+        #[test]
+        fn handles_ascending() {
+            let mut store = Ordered::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 2);
+            assert_eq!(store.min(), Some((&"b", &2)));
+        }
+
+        #[test]
+        fn handles_descending() {
+            let mut store: Ordered<_, _, HashMap<_, _>, _> =
+                Ordered::with_comparator(Descending);
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            assert_eq!(store.min(), Some((&"a", &4)));
+        }
+
+        #[test]
+        fn handles_empty() {
+            let store = Ordered::<&str, i32>::default();
+            assert_eq!(store.min(), None);
+        }
+    }
+
+    mod max {
+        use std::collections::HashMap;
+
+        use crate::comparator::Descending;
+        use crate::decorator::Ordered;
+        use crate::{StoreMut, StoreWithComparator};
+
+        #[test]
+        fn handles_ascending() {
+            let mut store = Ordered::default();
+            store.insert("a", 4);
+            store.insert("b", 4);
+            store.insert("c", 2);
+            assert_eq!(store.max(), Some((&"a", &4)));
+        }
+
+        #[test]
+        fn handles_descending() {
+            let mut store: Ordered<_, _, HashMap<_, _>, _> =
+                Ordered::with_comparator(Descending);
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            assert_eq!(store.max(), Some((&"b", &2)));
+        }
+
+        #[test]
+        fn handles_empty() {
+            let store = Ordered::<&str, i32>::default();
+            assert_eq!(store.max(), None);
+        }
+    }
+
+    mod rank_of {
+        use std::collections::HashMap;
+
+        use crate::comparator::Descending;
+        use crate::decorator::Ordered;
+        use crate::{StoreIterable, StoreMut, StoreWithComparator};
+
+        #[test]
+        fn handles_duplicates() {
+            let mut store = Ordered::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 4);
+            store.insert("d", 2);
+            store.insert("e", 1);
+            for (rank, (key, _)) in store.iter().enumerate() {
+                assert_eq!(store.rank_of(key), Some(rank));
+            }
+            assert_eq!(store.rank_of(&"d"), Some(2));
+            assert_eq!(store.rank_of(&"c"), Some(4));
+        }
+
+        #[test]
+        fn handles_descending() {
+            let mut store: Ordered<_, _, HashMap<_, _>, _> =
+                Ordered::with_comparator(Descending);
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 4);
+            for (rank, (key, _)) in store.iter().enumerate() {
+                assert_eq!(store.rank_of(key), Some(rank));
+            }
+        }
+
+        #[test]
+        fn handles_missing() {
+            let mut store = Ordered::default();
+            store.insert("a", 4);
+            assert_eq!(store.rank_of(&"b"), None);
+        }
+    }
+
+    mod pop {
+        use std::collections::HashMap;
+
+        use crate::comparator::Descending;
+        use crate::decorator::Ordered;
+        use crate::{Store, StoreMut, StoreWithComparator};
+
+        #[test]
+        fn handles_ascending() {
+            let mut store = Ordered::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            store.insert("d", 1);
+            assert_eq!(store.pop(), Some(("d", 1)));
+            assert_eq!(store.pop(), Some(("b", 2)));
+            assert_eq!(store.pop(), Some(("c", 3)));
+            assert_eq!(store.pop(), Some(("a", 4)));
+            assert_eq!(store.pop(), None);
+            assert!(store.is_empty());
+        }
+
+        #[test]
+        fn handles_descending() {
+            let mut store: Ordered<_, _, HashMap<_, _>, _> =
+                Ordered::with_comparator(Descending);
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            assert_eq!(store.pop(), Some(("a", 4)));
+            assert_eq!(store.pop(), Some(("c", 3)));
+            assert_eq!(store.pop(), Some(("b", 2)));
+            assert_eq!(store.pop(), None);
+        }
+
+        #[test]
+        fn handles_removal() {
+            let mut store = Ordered::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 2);
+            store.remove(&"b");
+            assert_eq!(store.pop(), Some(("c", 2)));
+            assert_eq!(store.get(&"c"), None);
+            assert_eq!(store.len(), 1);
+            assert_eq!(store.pop(), Some(("a", 4)));
+            assert_eq!(store.pop(), None);
+        }
+    }
+
+    mod update {
+        use crate::decorator::Ordered;
+        use crate::{Store, StoreIterable, StoreMut};
+
+        #[test]
+        fn handles_reposition() {
+            let mut store = Ordered::default();
+            store.insert("a", 1);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            assert!(store.update(&"a", |value| *value = 4));
+            assert_eq!(
+                store.iter().collect::<Vec<_>>(),
+                Vec::from([(&"b", &2), (&"c", &3), (&"a", &4)])
+            );
+            assert_eq!(store.check_invariants(), Ok(()));
+        }
+
+        #[test]
+        fn handles_unchanged() {
+            let mut store = Ordered::default();
+            store.insert("a", 1);
+            store.insert("b", 1);
+            assert!(store.update(&"a", |_| {}));
+            assert_eq!(
+                store.iter().collect::<Vec<_>>(),
+                Vec::from([(&"a", &1), (&"b", &1)])
+            );
+        }
+
+        #[test]
+        fn handles_missing() {
+            let mut store = Ordered::<_, i32>::default();
+            store.insert("a", 1);
+            assert!(!store.update(&"b", |value| *value = 2));
+            assert_eq!(store.get(&"b"), None);
+        }
+    }
+
+    mod iter_mut_then_resort {
+        use std::panic::{self, AssertUnwindSafe};
+
+        use crate::decorator::Ordered;
+        use crate::{StoreIterable, StoreMut};
+
+        #[test]
+        fn handles_inversion() {
+            let mut store = Ordered::default();
+            store.insert("a", 1);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            store.iter_mut_then_resort(|_, value| *value = 10 - *value);
+            assert_eq!(
+                store.iter().collect::<Vec<_>>(),
+                Vec::from([(&"c", &7), (&"b", &8), (&"a", &9)])
+            );
+            assert_eq!(store.check_invariants(), Ok(()));
+        }
+
+        #[test]
+        fn handles_equal() {
+            let mut store = Ordered::default();
+            store.insert("a", 3);
+            store.insert("b", 2);
+            store.insert("c", 1);
+            store.iter_mut_then_resort(|_, value| *value = 0);
+            assert_eq!(
+                store.iter().collect::<Vec<_>>(),
+                Vec::from([(&"c", &0), (&"b", &0), (&"a", &0)])
+            );
+        }
+
+        #[test]
+        fn handles_panic() {
+            let mut store = Ordered::default();
+            store.insert("a", 1);
+            store.insert("b", 2);
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                store.iter_mut_then_resort(|key, value| {
+                    assert_eq!(key, &"a", "panicked");
+                    *value = 0;
+                });
+            }));
+            assert!(result.is_err());
+            assert_eq!(
+                store.iter().collect::<Vec<_>>(),
+                Vec::from([(&"a", &1), (&"b", &2)])
+            );
+            assert_eq!(store.check_invariants(), Ok(()));
+            StoreMut::remove(&mut store, &"a");
+            assert_eq!(
+                store.iter().collect::<Vec<_>>(),
+                Vec::from([(&"b", &2)])
+            );
+            assert_eq!(store.check_invariants(), Ok(()));
+        }
+    }
+
+    mod check_invariants {
+        use crate::decorator::Ordered;
+        use crate::StoreMut;
+
+        #[test]
+        fn handles_valid() {
+            let mut store = Ordered::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 2);
+            store.insert("a", 2);
+            store.remove(&"b");
+            assert_eq!(store.check_invariants(), Ok(()));
+        }
+
+        #[test]
+        fn handles_invalid() {
+            let mut store = Ordered::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.store.insert("a", 3);
+            assert!(store.check_invariants().is_err());
+        }
+    }
+
+    mod capacity {
+        use crate::decorator::Ordered;
+        use crate::{Store, StoreMut, StoreStats};
+
+        #[test]
+        fn handles_insert() {
+            let mut store = Ordered::default();
+            for n in 0..100 {
+                store.insert(n, n % 10);
+            }
+            assert!(store.capacity() >= 100);
+        }
+
+        #[test]
+        fn handles_removal() {
+            let mut store = Ordered::default();
+            for n in 0..100 {
+                store.insert(n, n % 10);
+            }
+            for n in 0..90 {
+                store.remove(&n);
+            }
+            assert_eq!(store.len(), 10);
+            assert!(store.capacity() > store.len());
+            store.shrink_to_fit();
+            assert!(store.capacity() >= store.len());
+            assert!(store.capacity() < 100);
+        }
+    }
+
+    mod with_comparator {
+        use std::collections::HashMap;
+
+        use crate::comparator::{ByKey, Reverse};
+        use crate::decorator::Ordered;
+        use crate::{StoreIterable, StoreMut, StoreWithComparator};
+
+        #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Event {
+            name: &'static str,
+            time: u64,
+        }
+
+        #[test]
+        fn handles_projection() {
+            let comparator = ByKey::new(|event: &Event| event.time);
+            let mut store: Ordered<_, _, HashMap<_, _>, _> =
+                Ordered::with_comparator(comparator);
+            store.insert("a", Event { name: "a", time: 3 });
+            store.insert("b", Event { name: "z", time: 1 });
+            store.insert("c", Event { name: "m", time: 2 });
+            store.insert("d", Event { name: "b", time: 2 });
+            assert_eq!(
+                store.iter().map(|(key, _)| *key).collect::<Vec<_>>(),
+                Vec::from(["b", "d", "c", "a"])
+            );
+            assert_eq!(store.pop(), Some(("b", Event { name: "z", time: 1 })));
+        }
+
+        #[test]
+        fn handles_reverse() {
+            let comparator = ByKey::new(|event: &Event| event.time);
+            let mut store: Ordered<_, _, HashMap<_, _>, _> =
+                Ordered::with_comparator(Reverse::new(comparator));
+            store.insert("a", Event { name: "a", time: 3 });
+            store.insert("b", Event { name: "z", time: 1 });
+            store.insert("c", Event { name: "m", time: 2 });
+            store.insert("d", Event { name: "b", time: 2 });
+            assert_eq!(
+                store.iter().map(|(key, _)| *key).collect::<Vec<_>>(),
+                Vec::from(["a", "c", "d", "b"])
+            );
+        }
+    }
+
+    mod extend {
+        use crate::decorator::Ordered;
+        use crate::{Store, StoreIterable, StoreMut};
+
+        #[test]
+        fn handles_items() {
+            let mut store = Ordered::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.extend([("c", 3), ("d", 1), ("a", 5)]);
+            assert_eq!(store.len(), 4);
+            assert_eq!(store.get(&"a"), Some(&5));
+            assert_eq!(
+                store.iter().collect::<Vec<_>>(),
+                vec![(&"d", &1), (&"b", &2), (&"c", &3), (&"a", &5)]
+            );
+        }
+    }
+}
@@ -26,11 +26,12 @@
 //! Iterator implementations for [`Ordered`].
 
 use std::collections::btree_map;
+use std::ops::Bound;
 use std::slice;
 
-use crate::store::comparator::{Ascending, Comparable};
+use crate::store::comparator::{Ascending, Comparable, Comparator};
 use crate::store::key::Key;
-use crate::store::{Store, StoreIterable, StoreKeys, StoreValues};
+use crate::store::{Store, StoreIterable, StoreKeys, StoreSeek, StoreValues};
 
 use super::Ordered;
 
@@ -169,6 +170,59 @@ where
     }
 }
 
+impl<K, V, S, C> StoreSeek<K, V> for Ordered<K, V, S, C>
+where
+    K: Key,
+    V: Clone + Eq,
+    S: Store<K, V>,
+    C: Comparator<V> + Clone,
+{
+    /// Creates an iterator over the items of a store, starting at the key.
+    ///
+    /// If the key is present, iteration starts at its position (inclusive),
+    /// which is found via a range query on the ordering. Otherwise, the
+    /// iterator is empty, as an absent key has no value that could be used to
+    /// determine its position in the ordering, which is sorted by value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Ordered;
+    /// use zrx_store::{StoreMut, StoreSeek};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Ordered::default();
+    /// store.insert("a", 42);
+    /// store.insert("b", 84);
+    ///
+    /// // Create iterator over the store
+    /// for (key, value) in store.iter_from(&"b") {
+    ///     println!("{key}: {value}");
+    /// }
+    /// ```
+    #[inline]
+    fn iter_from<'a>(&'a self, key: &K) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        let key = key.clone();
+        self.store.get(&key).into_iter().flat_map(move |value| {
+            let value = Comparable::new(value.clone(), self.comparator.clone());
+
+            // Keys sharing a value are kept in insertion order, so we skip all
+            // keys preceding the given key in the first range entry
+            let key = key.clone();
+            self.ordering
+                .range((Bound::Included(value), Bound::Unbounded))
+                .flat_map(|(value, keys)| {
+                    keys.iter().map(move |key| (key, &**value))
+                })
+                .skip_while(move |(check, _)| *check != &key)
+        })
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 impl<'a, K, V, C> Iterator for Iter<'a, K, V, C>
@@ -264,3 +318,69 @@ where
         self.ordering.size_hint()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod iter_from {
+        use crate::decorator::Ordered;
+        use crate::{StoreMut, StoreSeek};
+
+        #[test]
+        fn handles_present() {
+            let mut store = Ordered::default();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            store.insert("d", 1);
+            assert_eq!(
+                store.iter_from(&"b").collect::<Vec<_>>(),
+                vec![(&"b", &2), (&"c", &3), (&"a", &4)]
+            );
+        }
+
+        #[test]
+        fn handles_present_first() {
+            let mut store = Ordered::default();
+            store.insert("a", 1);
+            store.insert("b", 2);
+            assert_eq!(
+                store.iter_from(&"a").collect::<Vec<_>>(),
+                vec![(&"a", &1), (&"b", &2)]
+            );
+        }
+
+        #[test]
+        fn handles_duplicate_values() {
+            let mut store = Ordered::default();
+            store.insert("a", 1);
+            store.insert("b", 1);
+            store.insert("c", 1);
+            assert_eq!(
+                store.iter_from(&"b").collect::<Vec<_>>(),
+                vec![(&"b", &1), (&"c", &1)]
+            );
+        }
+
+        #[test]
+        fn handles_absent() {
+            let mut store = Ordered::default();
+            store.insert("a", 3);
+            store.insert("b", 1);
+            store.insert("c", 2);
+            assert_eq!(store.iter_from(&"bb").count(), 0);
+        }
+
+        #[test]
+        fn handles_absent_last() {
+            let mut store = Ordered::default();
+            store.insert("a", 1);
+            store.insert("c", 3);
+            assert_eq!(store.iter_from(&"d").count(), 0);
+        }
+    }
+}
@@ -0,0 +1,1098 @@
+// Copyright (c) 2025-2026 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// All contributions are certified under the DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Passthrough decorator, delegating all operations to a store.
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::RangeBounds;
+
+use crate::store::behavior::StoreRestore;
+use crate::store::comparator::Comparator;
+use crate::store::key::Key;
+use crate::store::{
+    Store, StoreIterable, StoreIterableMut, StoreKeyValue, StoreKeys, StoreMut,
+    StoreMutRef, StoreRange, StoreSeek, StoreStats, StoreTransform,
+    StoreValues, StoreWithComparator,
+};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Passthrough decorator, delegating all operations to a store.
+///
+/// This is an identity wrapper around a [`Store`], which implements all store
+/// traits that the underlying store implements by delegating to it, without
+/// adding any behavior. It's useful as a base when composing decorators in a
+/// generic fashion, and as a test fixture to verify that decorators can be
+/// stacked on top of other decorators.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use zrx_store::decorator::Passthrough;
+/// use zrx_store::{Store, StoreMut};
+///
+/// // Create passthrough decorator over a store
+/// let mut store = Passthrough::new(HashMap::new());
+/// store.insert("key", 42);
+///
+/// // Obtain reference to value
+/// let value = store.get(&"key");
+/// assert_eq!(value, Some(&42));
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Passthrough<K, V, S>
+where
+    K: Key,
+    S: Store<K, V>,
+{
+    /// Underlying store.
+    store: S,
+    /// Capture types.
+    marker: PhantomData<(K, V)>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<K, V, S> Passthrough<K, V, S>
+where
+    K: Key,
+    S: Store<K, V>,
+{
+    /// Creates a passthrough decorator over a store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    ///
+    /// // Create passthrough decorator over a store
+    /// let store = HashMap::<&str, i32>::new();
+    /// let store = Passthrough::new(store);
+    /// ```
+    #[inline]
+    pub fn new(store: S) -> Self {
+        Self { store, marker: PhantomData }
+    }
+
+    /// Returns the underlying store, consuming the passthrough decorator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    ///
+    /// // Create passthrough decorator over a store
+    /// let store = HashMap::<&str, i32>::new();
+    /// let store = Passthrough::new(store);
+    ///
+    /// // Obtain underlying store
+    /// let store = store.into_inner();
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.store
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<K, V, S> Store<K, V> for Passthrough<K, V, S>
+where
+    K: Key,
+    S: Store<K, V>,
+{
+    /// Returns a reference to the value identified by the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Passthrough::new(HashMap::new());
+    /// store.insert("key", 42);
+    ///
+    /// // Obtain reference to value
+    /// let value = store.get(&"key");
+    /// assert_eq!(value, Some(&42));
+    /// ```
+    #[inline]
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        self.store.get(key)
+    }
+
+    /// Returns whether the store contains the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Passthrough::new(HashMap::new());
+    /// store.insert("key", 42);
+    ///
+    /// // Ensure presence of key
+    /// let check = store.contains_key(&"key");
+    /// assert_eq!(check, true);
+    /// ```
+    #[inline]
+    fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        self.store.contains_key(key)
+    }
+
+    /// Returns the number of items in the store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Passthrough::new(HashMap::new());
+    /// store.insert("key", 42);
+    ///
+    /// // Obtain number of items
+    /// let len = store.len();
+    /// assert_eq!(len, 1);
+    /// ```
+    #[inline]
+    fn len(&self) -> usize {
+        self.store.len()
+    }
+
+    /// Returns whether the store is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::Store;
+    ///
+    /// // Create store
+    /// let store = Passthrough::new(HashMap::<&str, i32>::new());
+    ///
+    /// // Ensure store is empty
+    /// let check = store.is_empty();
+    /// assert_eq!(check, true);
+    /// ```
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.store.is_empty()
+    }
+}
+
+impl<K, V, S> StoreKeyValue<K, V> for Passthrough<K, V, S>
+where
+    K: Key,
+    S: StoreKeyValue<K, V>,
+{
+    /// Returns references to the key and value identified by the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::{StoreKeyValue, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Passthrough::new(HashMap::new());
+    /// store.insert("key", 42);
+    ///
+    /// // Obtain references to key and value
+    /// let entry = store.get_key_value(&"key");
+    /// assert_eq!(entry, Some((&"key", &42)));
+    /// ```
+    #[inline]
+    fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        self.store.get_key_value(key)
+    }
+}
+
+impl<K, V, S> StoreMut<K, V> for Passthrough<K, V, S>
+where
+    K: Key,
+    S: StoreMut<K, V>,
+{
+    /// Inserts the value identified by the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store
+    /// let mut store = Passthrough::new(HashMap::new());
+    ///
+    /// // Insert value
+    /// let value = store.insert("key", 42);
+    /// assert_eq!(value, None);
+    /// ```
+    #[inline]
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.store.insert(key, value)
+    }
+
+    /// Inserts the value identified by the key if it changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Passthrough::new(HashMap::new());
+    /// store.insert("key", 42);
+    ///
+    /// // Insert value if changed
+    /// let check = store.insert_if_changed(&"key", &42);
+    /// assert_eq!(check, false);
+    /// ```
+    #[inline]
+    fn insert_if_changed(&mut self, key: &K, value: &V) -> bool
+    where
+        V: Clone + Eq,
+    {
+        self.store.insert_if_changed(key, value)
+    }
+
+    /// Removes the value identified by the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Passthrough::new(HashMap::new());
+    /// store.insert("key", 42);
+    ///
+    /// // Remove value
+    /// let value = store.remove(&"key");
+    /// assert_eq!(value, Some(42));
+    /// ```
+    #[inline]
+    fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        self.store.remove(key)
+    }
+
+    /// Removes the value identified by the key and returns both.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Passthrough::new(HashMap::new());
+    /// store.insert("key", 42);
+    ///
+    /// // Remove key and value
+    /// let entry = store.remove_entry(&"key");
+    /// assert_eq!(entry, Some(("key", 42)));
+    /// ```
+    #[inline]
+    fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        self.store.remove_entry(key)
+    }
+
+    /// Removes the values identified by the keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Passthrough::new(HashMap::new());
+    /// store.insert("a", 42);
+    /// store.insert("b", 84);
+    ///
+    /// // Remove values
+    /// let count = store.remove_all(["a", "b", "c"]);
+    /// assert_eq!(count, 2);
+    /// ```
+    #[inline]
+    fn remove_all<I>(&mut self, keys: I) -> usize
+    where
+        I: IntoIterator<Item = K>,
+    {
+        self.store.remove_all(keys)
+    }
+
+    /// Clears the store, removing all items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Passthrough::new(HashMap::new());
+    /// store.insert("key", 42);
+    ///
+    /// // Clear store
+    /// store.clear();
+    /// assert!(store.is_empty());
+    /// ```
+    #[inline]
+    fn clear(&mut self) {
+        self.store.clear();
+    }
+
+    /// Shrinks the capacity of the store as much as possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::{StoreMut, StoreStats};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Passthrough::new(HashMap::with_capacity(64));
+    /// store.insert("key", 42);
+    ///
+    /// // Shrink capacity of store
+    /// store.shrink_to_fit();
+    /// assert!(store.capacity() < 64);
+    /// ```
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        self.store.shrink_to_fit();
+    }
+}
+
+impl<K, V, S> StoreMutRef<K, V> for Passthrough<K, V, S>
+where
+    K: Key,
+    S: StoreMutRef<K, V> + StoreMut<K, V>,
+{
+    /// Returns a mutable reference to the value identified by the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::{StoreMut, StoreMutRef};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Passthrough::new(HashMap::new());
+    /// store.insert("key", 42);
+    ///
+    /// // Obtain mutable reference to value
+    /// let mut value = store.get_mut(&"key");
+    /// assert_eq!(value, Some(&mut 42));
+    /// ```
+    #[inline]
+    fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        self.store.get_mut(key)
+    }
+
+    /// Returns a mutable reference to the value or creates the default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::StoreMutRef;
+    ///
+    /// // Create store
+    /// let mut store = Passthrough::new(HashMap::<_, i32>::new());
+    ///
+    /// // Obtain mutable reference to value
+    /// let value = store.get_or_insert_default(&"key");
+    /// assert_eq!(value, &mut 0);
+    /// ```
+    #[inline]
+    fn get_or_insert_default(&mut self, key: &K) -> &mut V
+    where
+        V: Default,
+    {
+        self.store.get_or_insert_default(key)
+    }
+
+    /// Returns a mutable reference to the value or creates it with the given
+    /// function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::StoreMutRef;
+    ///
+    /// // Create store
+    /// let mut store = Passthrough::new(HashMap::new());
+    ///
+    /// // Obtain mutable reference to value
+    /// let value = store.get_or_insert_with(&"key", || 42);
+    /// assert_eq!(value, &mut 42);
+    /// ```
+    #[inline]
+    fn get_or_insert_with<F>(&mut self, key: &K, f: F) -> &mut V
+    where
+        F: FnOnce() -> V,
+    {
+        self.store.get_or_insert_with(key, f)
+    }
+}
+
+impl<K, V, S> StoreIterable<K, V> for Passthrough<K, V, S>
+where
+    K: Key,
+    S: StoreIterable<K, V>,
+{
+    type Iter<'a> = S::Iter<'a>
+    where
+        Self: 'a;
+
+    /// Creates an iterator over the items of a store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::{StoreIterable, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Passthrough::new(HashMap::new());
+    /// store.insert("key", 42);
+    ///
+    /// // Create iterator over the store
+    /// for (key, value) in store.iter() {
+    ///     println!("{key}: {value}");
+    /// }
+    /// ```
+    #[inline]
+    fn iter(&self) -> Self::Iter<'_> {
+        self.store.iter()
+    }
+}
+
+impl<K, V, S> StoreIterableMut<K, V> for Passthrough<K, V, S>
+where
+    K: Key,
+    S: StoreIterableMut<K, V>,
+{
+    type IterMut<'a> = S::IterMut<'a>
+    where
+        Self: 'a;
+
+    /// Creates a mutable iterator over the items of a store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::{StoreIterableMut, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Passthrough::new(HashMap::new());
+    /// store.insert("key", 42);
+    ///
+    /// // Create iterator over the store
+    /// for (key, value) in store.iter_mut() {
+    ///     println!("{key}: {value}");
+    /// }
+    /// ```
+    #[inline]
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        self.store.iter_mut()
+    }
+}
+
+impl<K, V, S> StoreKeys<K, V> for Passthrough<K, V, S>
+where
+    K: Key,
+    S: StoreKeys<K, V>,
+{
+    type Keys<'a> = S::Keys<'a>
+    where
+        Self: 'a;
+
+    /// Creates an iterator over the keys of a store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::{StoreKeys, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Passthrough::new(HashMap::new());
+    /// store.insert("key", 42);
+    ///
+    /// // Create iterator over the store
+    /// for key in store.keys() {
+    ///     println!("{key}");
+    /// }
+    /// ```
+    #[inline]
+    fn keys(&self) -> Self::Keys<'_> {
+        self.store.keys()
+    }
+}
+
+impl<K, V, S> StoreValues<K, V> for Passthrough<K, V, S>
+where
+    K: Key,
+    S: StoreValues<K, V>,
+{
+    type Values<'a> = S::Values<'a>
+    where
+        Self: 'a;
+
+    /// Creates an iterator over the values of a store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::{StoreMut, StoreValues};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Passthrough::new(HashMap::new());
+    /// store.insert("key", 42);
+    ///
+    /// // Create iterator over the store
+    /// for value in store.values() {
+    ///     println!("{value}");
+    /// }
+    /// ```
+    #[inline]
+    fn values(&self) -> Self::Values<'_> {
+        self.store.values()
+    }
+}
+
+impl<K, V, S> StoreRange<K, V> for Passthrough<K, V, S>
+where
+    K: Key,
+    S: StoreRange<K, V>,
+{
+    type Range<'a> = S::Range<'a>
+    where
+        Self: 'a;
+
+    /// Creates an iterator over a range of items in a store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::{StoreMut, StoreRange};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Passthrough::new(BTreeMap::new());
+    /// store.insert("a", 42);
+    /// store.insert("b", 84);
+    ///
+    /// // Create iterator over the store
+    /// for (key, value) in store.range("b"..) {
+    ///     println!("{key}: {value}");
+    /// }
+    /// ```
+    #[inline]
+    fn range<R>(&self, range: R) -> Self::Range<'_>
+    where
+        R: RangeBounds<K>,
+    {
+        self.store.range(range)
+    }
+}
+
+impl<K, V, S> StoreSeek<K, V> for Passthrough<K, V, S>
+where
+    K: Key,
+    S: StoreSeek<K, V>,
+{
+    /// Creates an iterator over the items of a store, starting at the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::{Ordered, Passthrough};
+    /// use zrx_store::{StoreMut, StoreSeek};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Passthrough::new(Ordered::default());
+    /// store.insert("a", 42);
+    /// store.insert("b", 84);
+    ///
+    /// // Create iterator over the store
+    /// for (key, value) in store.iter_from(&"b") {
+    ///     println!("{key}: {value}");
+    /// }
+    /// ```
+    #[inline]
+    fn iter_from<'a>(&'a self, key: &K) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.store.iter_from(key)
+    }
+}
+
+impl<K, V, S> StoreStats<K, V> for Passthrough<K, V, S>
+where
+    K: Key,
+    S: StoreStats<K, V>,
+{
+    /// Returns the number of items the store can hold without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::{StoreMut, StoreStats};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Passthrough::new(HashMap::new());
+    /// store.insert("key", 42);
+    ///
+    /// // Obtain capacity of store
+    /// assert!(store.capacity() >= 1);
+    /// ```
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.store.capacity()
+    }
+}
+
+impl<K, V, S> StoreTransform<K, V> for Passthrough<K, V, S>
+where
+    K: Key,
+    S: StoreTransform<K, V>,
+{
+    /// Applies the given function to all items of the store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::{Store, StoreMut, StoreTransform};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Passthrough::new(HashMap::new());
+    /// store.insert("key", 42);
+    ///
+    /// // Transform all values in place
+    /// store.transform(|_, value| *value *= 2);
+    /// assert_eq!(store.get(&"key"), Some(&84));
+    /// ```
+    #[inline]
+    fn transform<F>(&mut self, f: F)
+    where
+        F: FnMut(&K, &mut V),
+    {
+        self.store.transform(f);
+    }
+}
+
+impl<K, V, S> StoreRestore<K, V> for Passthrough<K, V, S>
+where
+    K: Key,
+    S: Store<K, V> + StoreRestore<K, V>,
+{
+    type Checkpoint = S::Checkpoint;
+
+    /// Creates a checkpoint of all items of the store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::behavior::StoreRestore;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Passthrough::new(HashMap::new());
+    /// store.insert("key", 42);
+    ///
+    /// // Create checkpoint of store
+    /// let checkpoint = store.snapshot();
+    /// assert_eq!(checkpoint.len(), 1);
+    /// ```
+    #[inline]
+    fn snapshot(&self) -> Self::Checkpoint
+    where
+        V: Clone,
+    {
+        self.store.snapshot()
+    }
+
+    /// Restores the store to the given checkpoint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::behavior::StoreRestore;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Passthrough::new(HashMap::new());
+    /// store.insert("key", 42);
+    ///
+    /// // Create checkpoint and mutate store
+    /// let checkpoint = store.snapshot();
+    /// store.insert("key", 84);
+    ///
+    /// // Restore store to checkpoint
+    /// store.restore(checkpoint);
+    /// assert_eq!(store.get(&"key"), Some(&42));
+    /// ```
+    #[inline]
+    fn restore(&mut self, checkpoint: Self::Checkpoint) {
+        self.store.restore(checkpoint);
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<K, V, S, C> StoreWithComparator<K, V, C> for Passthrough<K, V, S>
+where
+    K: Key,
+    S: StoreWithComparator<K, V, C>,
+    C: Comparator<V>,
+{
+    /// Creates a passthrough decorator over a store with the given comparator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::comparator::Descending;
+    /// use zrx_store::decorator::{Ordered, Passthrough};
+    /// use zrx_store::{StoreMut, StoreWithComparator};
+    ///
+    /// // Create store
+    /// let mut store: Passthrough<_, _, Ordered<_, _, HashMap<_, _>, _>> =
+    ///     Passthrough::with_comparator(Descending);
+    ///
+    /// // Insert value
+    /// store.insert("key", 42);
+    /// ```
+    #[inline]
+    fn with_comparator(comparator: C) -> Self {
+        Self::new(S::with_comparator(comparator))
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<K, V, S> Default for Passthrough<K, V, S>
+where
+    K: Key,
+    S: Store<K, V> + Default,
+{
+    /// Creates a passthrough decorator over a default store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Passthrough;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store
+    /// let mut store = Passthrough::<_, _, HashMap<_, _>>::default();
+    ///
+    /// // Insert value
+    /// store.insert("key", 42);
+    /// ```
+    #[inline]
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<K, V, S> fmt::Debug for Passthrough<K, V, S>
+where
+    K: Key,
+    S: fmt::Debug + Store<K, V>,
+{
+    /// Formats the passthrough decorator for debugging.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Passthrough")
+            .field("store", &self.store)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod stacking {
+        use std::collections::{BTreeMap, HashMap};
+
+        use crate::behavior::StoreRestore;
+        use crate::decorator::{Indexed, Ordered, Passthrough};
+        use crate::{Store, StoreIterable, StoreMut, StoreRange, StoreStats};
+
+        #[test]
+        fn handles_ordered() {
+            let mut store =
+                Ordered::<_, _, Passthrough<_, _, HashMap<_, _>>>::new();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            store.insert("a", 1);
+            StoreMut::remove(&mut store, &"c");
+            assert_eq!(
+                store.iter().collect::<Vec<_>>(),
+                Vec::from([(&"a", &1), (&"b", &2)])
+            );
+            assert_eq!(store.check_invariants(), Ok(()));
+        }
+
+        #[test]
+        fn handles_indexed() {
+            let mut store =
+                Indexed::<_, _, Passthrough<_, _, HashMap<_, _>>>::new();
+            store.insert("a", 4);
+            store.insert("b", 2);
+            store.insert("c", 3);
+            assert_eq!(store.position_of(&"c"), Some(1));
+            assert_eq!(store.check_invariants(), Ok(()));
+        }
+
+        #[test]
+        fn handles_range() {
+            let store = Passthrough::new(BTreeMap::from([("a", 1), ("b", 2)]));
+            assert_eq!(store.range("b"..).collect::<Vec<_>>(), [(&"b", &2)]);
+            assert_eq!(store.len(), 2);
+        }
+
+        #[test]
+        fn handles_capacity() {
+            let mut store =
+                Ordered::<_, _, Passthrough<_, _, HashMap<_, _>>>::new();
+            store.insert("a", 1);
+            assert!(store.capacity() >= 1);
+        }
+
+        #[test]
+        fn handles_restore() {
+            let mut store =
+                Indexed::<_, _, Passthrough<_, _, HashMap<_, _>>>::new();
+            store.insert("a", 2);
+            let checkpoint = store.snapshot();
+            store.insert("b", 1);
+            store.restore(checkpoint);
+            assert_eq!(store.iter().collect::<Vec<_>>(), [(&"a", &2)]);
+            assert_eq!(store.check_invariants(), Ok(()));
+        }
+    }
+
+    mod delegation {
+        use std::borrow::Borrow;
+        use std::cell::Cell;
+        use std::collections::HashMap;
+
+        use crate::decorator::Passthrough;
+        use crate::store::key::Key;
+        use crate::{Store, StoreMut, StoreMutRef};
+
+        #[derive(Default)]
+        struct Probe {
+            store: HashMap<&'static str, i32>,
+            calls: Cell<usize>,
+        }
+
+        impl Store<&'static str, i32> for Probe {
+            fn get<Q>(&self, key: &Q) -> Option<&i32>
+            where
+                &'static str: Borrow<Q>,
+                Q: Key,
+            {
+                self.store.get(key)
+            }
+
+            fn contains_key<Q>(&self, key: &Q) -> bool
+            where
+                &'static str: Borrow<Q>,
+                Q: Key,
+            {
+                self.store.contains_key(key)
+            }
+
+            fn len(&self) -> usize {
+                self.store.len()
+            }
+
+            fn is_empty(&self) -> bool {
+                self.calls.set(self.calls.get() + 1);
+                self.store.is_empty()
+            }
+        }
+
+        impl StoreMut<&'static str, i32> for Probe {
+            fn insert(&mut self, key: &'static str, value: i32) -> Option<i32> {
+                self.store.insert(key, value)
+            }
+
+            fn remove<Q>(&mut self, key: &Q) -> Option<i32>
+            where
+                &'static str: Borrow<Q>,
+                Q: Key,
+            {
+                self.store.remove(key)
+            }
+
+            fn remove_entry<Q>(&mut self, key: &Q) -> Option<(&'static str, i32)>
+            where
+                &'static str: Borrow<Q>,
+                Q: Key,
+            {
+                self.store.remove_entry(key)
+            }
+
+            fn clear(&mut self) {
+                self.store.clear();
+            }
+        }
+
+        impl StoreMutRef<&'static str, i32> for Probe {
+            fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut i32>
+            where
+                &'static str: Borrow<Q>,
+                Q: Key,
+            {
+                self.store.get_mut(key)
+            }
+
+            fn get_or_insert_default(&mut self, key: &&'static str) -> &mut i32 {
+                self.store.entry(key).or_default()
+            }
+
+            fn get_or_insert_with<F>(
+                &mut self, key: &&'static str, f: F,
+            ) -> &mut i32
+            where
+                F: FnOnce() -> i32,
+            {
+                self.calls.set(self.calls.get() + 1);
+                self.store.entry(key).or_insert_with(f)
+            }
+        }
+
+        #[test]
+        fn handles_is_empty() {
+            let store = Passthrough::new(Probe::default());
+            assert!(store.is_empty());
+            assert_eq!(store.into_inner().calls.get(), 1);
+        }
+
+        #[test]
+        fn handles_get_or_insert_with() {
+            let count = Cell::new(0);
+            let init = || {
+                count.set(count.get() + 1);
+                1
+            };
+            let mut store = Passthrough::new(Probe::default());
+            *store.get_or_insert_with(&"a", init) += 1;
+            *store.get_or_insert_with(&"a", init) += 1;
+            assert_eq!(store.get(&"a"), Some(&3));
+            assert_eq!(count.get(), 1);
+            assert_eq!(store.into_inner().calls.get(), 2);
+        }
+    }
+}